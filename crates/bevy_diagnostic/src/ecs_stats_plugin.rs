@@ -0,0 +1,124 @@
+use bevy_app::prelude::*;
+use bevy_ecs::{archetype::ArchetypeId, prelude::*};
+
+/// Adds an [`EcsStats`] resource to an App, updated once per frame.
+///
+/// Collecting the statistics walks every archetype and table, so only add this plugin
+/// when something consumes them, like a diagnostics overlay.
+/// For the archetypes and entities matched by a specific query, see
+/// [`QueryDiagnosticsPlugin`](crate::QueryDiagnosticsPlugin).
+#[derive(Default)]
+pub struct EcsStatsPlugin;
+
+impl Plugin for EcsStatsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<EcsStats>()
+            .add_systems(Last, Self::stats_system);
+    }
+}
+
+impl EcsStatsPlugin {
+    /// Updates the [`EcsStats`] resource from the world it is stored in.
+    pub fn stats_system(world: &mut World) {
+        world.resource_scope(|world, mut stats: Mut<EcsStats>| stats.update(world));
+    }
+}
+
+/// Statistics about how a [`World`] stores its entities.
+///
+/// Updated every frame by [`EcsStatsPlugin`].
+#[derive(Resource, Debug, Default, Clone)]
+pub struct EcsStats {
+    /// The number of entities in the world.
+    pub entity_count: u32,
+    /// The number of entities in each non-empty archetype.
+    pub archetype_entity_counts: Vec<(ArchetypeId, usize)>,
+    /// The usage of each table, indexed by [`TableId::as_usize`](bevy_ecs::storage::TableId::as_usize).
+    pub tables: Vec<TableStats>,
+}
+
+/// The usage of a single table, as reported in [`EcsStats::tables`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct TableStats {
+    /// The number of entities stored in the table.
+    pub entity_count: usize,
+    /// The number of entities the table can store without reallocating.
+    pub entity_capacity: usize,
+}
+
+impl EcsStats {
+    /// Recomputes the statistics from `world`, reusing the existing allocations.
+    pub fn update(&mut self, world: &World) {
+        self.entity_count = world.entities().len();
+
+        self.archetype_entity_counts.clear();
+        self.archetype_entity_counts.extend(
+            world
+                .archetypes()
+                .iter()
+                .filter(|archetype| !archetype.is_empty())
+                .map(|archetype| (archetype.id(), archetype.len())),
+        );
+
+        self.tables.clear();
+        self.tables
+            .extend(world.storages().tables.iter().map(|table| TableStats {
+                entity_count: table.entity_count(),
+                entity_capacity: table.entity_capacity(),
+            }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use super::EcsStats;
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct B;
+
+    #[test]
+    fn stats_match_world_storage() {
+        let mut world = World::new();
+        let a1 = world.spawn(A).id();
+        world.spawn(A);
+        let ab = world.spawn((A, B)).id();
+        world.spawn_empty().despawn();
+
+        let mut stats = EcsStats::default();
+        stats.update(&world);
+
+        assert_eq!(stats.entity_count, 3);
+        assert_eq!(
+            stats.archetype_entity_counts,
+            vec![
+                (world.entity(a1).archetype().id(), 2),
+                (world.entity(ab).archetype().id(), 1),
+            ]
+        );
+        let table_counts: Vec<usize> = stats
+            .tables
+            .iter()
+            .map(|table| table.entity_count)
+            .collect();
+        // The empty table always exists, even when no entity is stored in it.
+        assert_eq!(table_counts, vec![0, 2, 1]);
+        assert!(stats
+            .tables
+            .iter()
+            .all(|table| table.entity_capacity >= table.entity_count));
+
+        // Statistics are recomputed from scratch on every update.
+        world.despawn(ab);
+        stats.update(&world);
+        assert_eq!(stats.entity_count, 2);
+        assert_eq!(
+            stats.archetype_entity_counts,
+            vec![(world.entity(a1).archetype().id(), 2)]
+        );
+    }
+}
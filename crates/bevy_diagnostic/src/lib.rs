@@ -12,17 +12,23 @@
 //! their ability to monitor and optimize their game's.
 
 mod diagnostic;
+#[cfg(feature = "ecs_stats_plugin")]
+mod ecs_stats_plugin;
 mod entity_count_diagnostics_plugin;
 mod frame_time_diagnostics_plugin;
 mod log_diagnostics_plugin;
+mod query_diagnostics_plugin;
 #[cfg(feature = "sysinfo_plugin")]
 mod system_information_diagnostics_plugin;
 
 pub use diagnostic::*;
 
+#[cfg(feature = "ecs_stats_plugin")]
+pub use ecs_stats_plugin::{EcsStats, EcsStatsPlugin, TableStats};
 pub use entity_count_diagnostics_plugin::EntityCountDiagnosticsPlugin;
 pub use frame_time_diagnostics_plugin::FrameTimeDiagnosticsPlugin;
 pub use log_diagnostics_plugin::LogDiagnosticsPlugin;
+pub use query_diagnostics_plugin::QueryDiagnosticsPlugin;
 #[cfg(feature = "sysinfo_plugin")]
 pub use system_information_diagnostics_plugin::{SystemInfo, SystemInformationDiagnosticsPlugin};
 
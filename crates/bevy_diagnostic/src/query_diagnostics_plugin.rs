@@ -0,0 +1,143 @@
+use std::{borrow::Cow, marker::PhantomData};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    archetype::Archetypes,
+    prelude::*,
+    query::{QueryFilter, ReadOnlyQueryData},
+};
+
+use crate::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
+
+/// Adds diagnostics about the cache of a `Query<D, F>` to an App, under `query/<name>/`.
+///
+/// - `query/<name>/matched_archetypes`: the number of archetypes matched by the query.
+/// - `query/<name>/matched_entities`: the number of entities in those archetypes.
+/// - `query/<name>/matched_storage_bytes`: see [`Query::matched_storage_bytes`].
+///
+/// The measurements come from a dedicated system, so they describe a query with the same
+/// data and filter as the one being investigated, updated against the same world.
+///
+/// ```
+/// # use bevy_app::App;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_diagnostic::QueryDiagnosticsPlugin;
+/// # #[derive(Component)]
+/// # struct Enemy;
+/// App::new().add_plugins(QueryDiagnosticsPlugin::<&Enemy>::new("enemies"));
+/// ```
+pub struct QueryDiagnosticsPlugin<D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static = ()> {
+    name: Cow<'static, str>,
+    marker: PhantomData<fn() -> (D, F)>,
+}
+
+impl<D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> QueryDiagnosticsPlugin<D, F> {
+    /// Creates a plugin reporting the diagnostics of `Query<D, F>` under `query/<name>/`.
+    pub fn new(name: impl Into<Cow<'static, str>>) -> Self {
+        Self {
+            name: name.into(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns the path of the `matched_archetypes` diagnostic of the query called `name`.
+    pub fn matched_archetypes_path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components(["query", name, "matched_archetypes"])
+    }
+
+    /// Returns the path of the `matched_entities` diagnostic of the query called `name`.
+    ///
+    /// Filters that don't restrict archetypes, like [`Changed`], aren't taken into account.
+    pub fn matched_entities_path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components(["query", name, "matched_entities"])
+    }
+
+    /// Returns the path of the `matched_storage_bytes` diagnostic of the query called `name`.
+    pub fn matched_storage_bytes_path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::from_components(["query", name, "matched_storage_bytes"])
+    }
+}
+
+impl<D: ReadOnlyQueryData + 'static, F: QueryFilter + 'static> Plugin
+    for QueryDiagnosticsPlugin<D, F>
+{
+    fn build(&self, app: &mut App) {
+        let archetype_count = Self::matched_archetypes_path(&self.name);
+        let entity_count = Self::matched_entities_path(&self.name);
+        let storage_bytes = Self::matched_storage_bytes_path(&self.name);
+        app.register_diagnostic(Diagnostic::new(archetype_count.clone()))
+            .register_diagnostic(Diagnostic::new(entity_count.clone()))
+            .register_diagnostic(Diagnostic::new(storage_bytes.clone()).with_suffix(" bytes"))
+            .add_systems(
+                Update,
+                move |mut diagnostics: Diagnostics, query: Query<D, F>, archetypes: &Archetypes| {
+                    diagnostics.add_measurement(&archetype_count, || {
+                        query.matched_archetypes().count() as f64
+                    });
+                    diagnostics.add_measurement(&entity_count, || {
+                        query
+                            .matched_archetypes()
+                            .map(|id| archetypes[id].len())
+                            .sum::<usize>() as f64
+                    });
+                    diagnostics
+                        .add_measurement(&storage_bytes, || query.matched_storage_bytes() as f64);
+                },
+            );
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_ecs::prelude::*;
+
+    use super::QueryDiagnosticsPlugin;
+    use crate::DiagnosticsStore;
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct B;
+
+    #[test]
+    fn reports_match_counts() {
+        let mut app = App::new();
+        app.add_plugins(QueryDiagnosticsPlugin::<&A>::new("a"));
+        app.world_mut().spawn(A);
+        app.world_mut().spawn(A);
+        app.world_mut().spawn((A, B));
+        app.world_mut().spawn(B);
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let value = |path| store.get(&path).unwrap().value();
+        assert_eq!(
+            Some(2.0),
+            value(QueryDiagnosticsPlugin::<&A>::matched_archetypes_path("a"))
+        );
+        assert_eq!(
+            Some(3.0),
+            value(QueryDiagnosticsPlugin::<&A>::matched_entities_path("a"))
+        );
+    }
+
+    #[test]
+    fn reports_matched_storage_bytes() {
+        let mut app = App::new();
+        app.add_plugins(QueryDiagnosticsPlugin::<&A>::new("a"));
+        app.world_mut().spawn(A);
+        app.world_mut().spawn((A, B));
+        app.update();
+
+        let expected = app.world_mut().query::<&A>().matched_storage_bytes();
+        let path = QueryDiagnosticsPlugin::<&A>::matched_storage_bytes_path("a");
+        let store = app.world().resource::<DiagnosticsStore>();
+        assert_eq!(Some(expected as f64), store.get(&path).unwrap().value());
+    }
+}
@@ -1,7 +1,9 @@
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
-use syn::{parse_macro_input, parse_quote, DeriveInput, Ident, LitStr, Path, Result};
+use syn::{
+    parse_macro_input, parse_quote, DeriveInput, Ident, LitBool, LitStr, Meta, Path, Result,
+};
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
@@ -25,6 +27,11 @@ pub fn derive_resource(input: TokenStream) -> TokenStream {
     let mut ast = parse_macro_input!(input as DeriveInput);
     let bevy_ecs_path: Path = crate::bevy_ecs_path();
 
+    let change_detection = match parse_resource_attr(&ast) {
+        Ok(change_detection) => change_detection,
+        Err(e) => return e.into_compile_error().into(),
+    };
+
     ast.generics
         .make_where_clause()
         .predicates
@@ -35,6 +42,7 @@ pub fn derive_resource(input: TokenStream) -> TokenStream {
 
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::system::Resource for #struct_name #type_generics #where_clause {
+            const CHANGE_DETECTION_ENABLED: bool = #change_detection;
         }
     })
 }
@@ -48,7 +56,30 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         Err(e) => return e.into_compile_error().into(),
     };
 
-    let storage = storage_path(&bevy_ecs_path, attrs.storage);
+    let storage = match &attrs.storage_condition {
+        Some(condition) => {
+            let conditional = storage_path(&bevy_ecs_path, attrs.storage);
+            let fallback = storage_path(&bevy_ecs_path, StorageTy::Table);
+            quote! {
+                if cfg!(#condition) { #conditional } else { #fallback }
+            }
+        }
+        None => storage_path(&bevy_ecs_path, attrs.storage),
+    };
+
+    let change_detection = attrs.change_detection;
+    let default_constructor = attrs.register_default.then(|| {
+        quote! {
+            const DEFAULT_CONSTRUCTOR: ::core::option::Option<fn() -> Self> =
+                ::core::option::Option::Some(<Self as ::core::default::Default>::default);
+        }
+    });
+    let removed_value = attrs.removed_value.then(|| {
+        quote! {
+            const REMOVED_VALUE: ::core::option::Option<fn(&Self) -> Self> =
+                ::core::option::Option::Some(<Self as ::core::clone::Clone>::clone);
+        }
+    });
 
     ast.generics
         .make_where_clause()
@@ -61,15 +92,29 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     TokenStream::from(quote! {
         impl #impl_generics #bevy_ecs_path::component::Component for #struct_name #type_generics #where_clause {
             const STORAGE_TYPE: #bevy_ecs_path::component::StorageType = #storage;
+            const CHANGE_DETECTION_ENABLED: bool = #change_detection;
+            #default_constructor
+            #removed_value
         }
     })
 }
 
+pub const RESOURCE: &str = "resource";
 pub const COMPONENT: &str = "component";
 pub const STORAGE: &str = "storage";
+pub const STORAGE_CONDITION: &str = "if";
+pub const CHANGE_DETECTION: &str = "change_detection";
+pub const REGISTER_DEFAULT: &str = "register_default";
+pub const REMOVED_VALUE: &str = "removed_value";
 
 struct Attrs {
     storage: StorageTy,
+    /// A `cfg` predicate which must hold for `storage` to be used, falling back to
+    /// [`StorageTy::Table`] otherwise.
+    storage_condition: Option<Meta>,
+    change_detection: bool,
+    register_default: bool,
+    removed_value: bool,
 }
 
 #[derive(Clone, Copy)]
@@ -85,7 +130,12 @@ const SPARSE_SET: &str = "SparseSet";
 fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
     let mut attrs = Attrs {
         storage: StorageTy::Table,
+        storage_condition: None,
+        change_detection: true,
+        register_default: false,
+        removed_value: false,
     };
+    let mut storage_set = false;
 
     for meta in ast.attrs.iter().filter(|a| a.path().is_ident(COMPONENT)) {
         meta.parse_nested_meta(|nested| {
@@ -99,6 +149,19 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
                         )));
                     }
                 };
+                storage_set = true;
+                Ok(())
+            } else if nested.path.is_ident(STORAGE_CONDITION) {
+                attrs.storage_condition = Some(nested.value()?.parse::<Meta>()?);
+                Ok(())
+            } else if nested.path.is_ident(CHANGE_DETECTION) {
+                attrs.change_detection = nested.value()?.parse::<LitBool>()?.value();
+                Ok(())
+            } else if nested.path.is_ident(REGISTER_DEFAULT) {
+                attrs.register_default = true;
+                Ok(())
+            } else if nested.path.is_ident(REMOVED_VALUE) {
+                attrs.removed_value = true;
                 Ok(())
             } else {
                 Err(nested.error("Unsupported attribute"))
@@ -106,9 +169,34 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         })?;
     }
 
+    if attrs.storage_condition.is_some() && !storage_set {
+        return Err(syn::Error::new(
+            Span::call_site(),
+            format!("`{STORAGE_CONDITION}` can only be used together with `{STORAGE}`."),
+        ));
+    }
+
     Ok(attrs)
 }
 
+/// Returns whether change detection is enabled for the resource.
+fn parse_resource_attr(ast: &DeriveInput) -> Result<bool> {
+    let mut change_detection = true;
+
+    for meta in ast.attrs.iter().filter(|a| a.path().is_ident(RESOURCE)) {
+        meta.parse_nested_meta(|nested| {
+            if nested.path.is_ident(CHANGE_DETECTION) {
+                change_detection = nested.value()?.parse::<LitBool>()?.value();
+                Ok(())
+            } else {
+                Err(nested.error("Unsupported attribute"))
+            }
+        })?;
+    }
+
+    Ok(change_detection)
+}
+
 fn storage_path(bevy_ecs_path: &Path, ty: StorageTy) -> TokenStream2 {
     let storage_type = match ty {
         StorageTy::Table => Ident::new("Table", Span::call_site()),
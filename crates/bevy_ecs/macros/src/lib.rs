@@ -5,6 +5,7 @@
 extern crate proc_macro;
 
 mod component;
+mod map_entities;
 mod query_data;
 mod query_filter;
 mod states;
@@ -41,6 +42,7 @@ pub fn derive_bundle(input: TokenStream) -> TokenStream {
     let mut field_kind = Vec::with_capacity(named_fields.len());
 
     for field in named_fields {
+        let mut kind = BundleFieldKind::Component;
         for attr in field
             .attrs
             .iter()
@@ -48,7 +50,7 @@ pub fn derive_bundle(input: TokenStream) -> TokenStream {
         {
             if let Err(error) = attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident(BUNDLE_ATTRIBUTE_IGNORE_NAME) {
-                    field_kind.push(BundleFieldKind::Ignore);
+                    kind = BundleFieldKind::Ignore;
                     Ok(())
                 } else {
                     Err(meta.error(format!(
@@ -60,7 +62,33 @@ pub fn derive_bundle(input: TokenStream) -> TokenStream {
             }
         }
 
-        field_kind.push(BundleFieldKind::Component);
+        field_kind.push(kind);
+    }
+
+    // Catch the most common source of conflicting components at compile time:
+    // the same component or bundle type being used for several fields.
+    // Conflicts hidden inside nested bundles are still reported when the bundle is registered.
+    let mut seen_types = Vec::with_capacity(named_fields.len());
+    for (field, kind) in named_fields.iter().zip(field_kind.iter()) {
+        if !matches!(kind, BundleFieldKind::Component) {
+            continue;
+        }
+        let ty = &field.ty;
+        let ty_name = quote!(#ty).to_string();
+        if seen_types.contains(&ty_name) {
+            return syn::Error::new(
+                ty.span(),
+                format!(
+                    "`{ty_name}` is used by more than one field of this bundle, \
+                    but a bundle cannot contain duplicate components. \
+                    Mark the extra fields with `#[{BUNDLE_ATTRIBUTE_NAME}({BUNDLE_ATTRIBUTE_IGNORE_NAME})]` \
+                    if they are not meant to be inserted."
+                ),
+            )
+            .into_compile_error()
+            .into();
+        }
+        seen_types.push(ty_name);
     }
 
     let field = named_fields
@@ -108,11 +136,19 @@ pub fn derive_bundle(input: TokenStream) -> TokenStream {
                 }
             }
 
-            BundleFieldKind::Ignore => {
-                field_from_components.push(quote! {
-                    #field: ::std::default::Default::default(),
-                });
-            }
+            BundleFieldKind::Ignore => match field {
+                Some(field) => {
+                    field_from_components.push(quote! {
+                        #field: ::std::default::Default::default(),
+                    });
+                }
+                None => {
+                    let index = syn::Index::from(i);
+                    field_from_components.push(quote! {
+                        #index: ::std::default::Default::default(),
+                    });
+                }
+            },
         }
     }
     let generics = ast.generics;
@@ -507,7 +543,7 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
     component::derive_event(input)
 }
 
-#[proc_macro_derive(Resource)]
+#[proc_macro_derive(Resource, attributes(resource))]
 pub fn derive_resource(input: TokenStream) -> TokenStream {
     component::derive_resource(input)
 }
@@ -517,6 +553,16 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     component::derive_component(input)
 }
 
+/// Derive macro generating an impl of the trait `MapEntities`.
+///
+/// Fields of type `Entity` are mapped automatically. Other fields containing entities,
+/// such as `Vec<Entity>` or types which themselves implement `MapEntities`, can be mapped
+/// by marking them with `#[entities]`.
+#[proc_macro_derive(MapEntities, attributes(entities))]
+pub fn derive_map_entities(input: TokenStream) -> TokenStream {
+    map_entities::derive_map_entities(input)
+}
+
 #[proc_macro_derive(States)]
 pub fn derive_states(input: TokenStream) -> TokenStream {
     states::derive_states(input)
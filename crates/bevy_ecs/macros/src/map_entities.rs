@@ -0,0 +1,117 @@
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Field, Fields, Index, Member, Path,
+    Type,
+};
+
+pub const ENTITIES: &str = "entities";
+
+pub fn derive_map_entities(input: TokenStream) -> TokenStream {
+    let ast = parse_macro_input!(input as DeriveInput);
+    let bevy_ecs_path: Path = crate::bevy_ecs_path();
+
+    let map_fields = match &ast.data {
+        Data::Struct(data) => {
+            let members = data
+                .fields
+                .iter()
+                .enumerate()
+                .filter(|(_, field)| is_mapped(field))
+                .map(|(index, field)| match &field.ident {
+                    Some(ident) => Member::Named(ident.clone()),
+                    None => Member::Unnamed(Index::from(index)),
+                });
+            quote! {
+                #(#bevy_ecs_path::entity::MapEntities::map_entities(&mut self.#members, entity_mapper);)*
+            }
+        }
+        Data::Enum(data) => {
+            let arms = data.variants.iter().map(|variant| {
+                let ident = &variant.ident;
+                match &variant.fields {
+                    Fields::Named(fields) => {
+                        let bindings: Vec<_> = fields
+                            .named
+                            .iter()
+                            .filter(|field| is_mapped(field))
+                            .map(|field| field.ident.clone())
+                            .collect();
+                        quote! {
+                            Self::#ident { #(#bindings,)* .. } => {
+                                #(#bevy_ecs_path::entity::MapEntities::map_entities(#bindings, entity_mapper);)*
+                            }
+                        }
+                    }
+                    Fields::Unnamed(fields) => {
+                        let patterns = fields.unnamed.iter().enumerate().map(|(index, field)| {
+                            if is_mapped(field) {
+                                let binding = format_ident!("field_{}", index);
+                                quote! { #binding }
+                            } else {
+                                quote! { _ }
+                            }
+                        });
+                        let bindings = fields
+                            .unnamed
+                            .iter()
+                            .enumerate()
+                            .filter(|(_, field)| is_mapped(field))
+                            .map(|(index, _)| format_ident!("field_{}", index));
+                        quote! {
+                            Self::#ident(#(#patterns,)*) => {
+                                #(#bevy_ecs_path::entity::MapEntities::map_entities(#bindings, entity_mapper);)*
+                            }
+                        }
+                    }
+                    Fields::Unit => quote! {
+                        Self::#ident => {}
+                    },
+                }
+            });
+            quote! {
+                match self {
+                    #(#arms)*
+                }
+            }
+        }
+        Data::Union(_) => {
+            return syn::Error::new(ast.span(), "MapEntities cannot be derived for unions.")
+                .into_compile_error()
+                .into();
+        }
+    };
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    TokenStream::from(quote! {
+        impl #impl_generics #bevy_ecs_path::entity::MapEntities for #struct_name #type_generics #where_clause {
+            #[allow(unused_variables)]
+            fn map_entities<__M: #bevy_ecs_path::entity::EntityMapper>(&mut self, entity_mapper: &mut __M) {
+                #map_fields
+            }
+        }
+    })
+}
+
+/// Fields are mapped if they are marked with `#[entities]` or are a plain `Entity`.
+fn is_mapped(field: &Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .any(|attr| attr.path().is_ident(ENTITIES))
+        || is_entity(&field.ty)
+}
+
+fn is_entity(ty: &Type) -> bool {
+    let Type::Path(type_path) = ty else {
+        return false;
+    };
+    type_path.qself.is_none()
+        && type_path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "Entity" && segment.arguments.is_none())
+}
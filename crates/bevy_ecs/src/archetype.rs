@@ -668,6 +668,7 @@ pub struct Archetypes {
     pub(crate) archetypes: Vec<Archetype>,
     archetype_component_count: usize,
     by_components: bevy_utils::HashMap<ArchetypeComponents, ArchetypeId>,
+    by_component: bevy_utils::HashMap<ComponentId, Vec<ArchetypeId>>,
 }
 
 impl Archetypes {
@@ -675,6 +676,7 @@ impl Archetypes {
         let mut archetypes = Archetypes {
             archetypes: Vec::new(),
             by_components: Default::default(),
+            by_component: Default::default(),
             archetype_component_count: 0,
         };
         // SAFETY: Empty archetype has no components
@@ -773,6 +775,28 @@ impl Archetypes {
         self.archetypes.iter()
     }
 
+    /// Returns the ids of all archetypes containing the component `component_id`, in creation order.
+    ///
+    /// This index is updated as archetypes are created, so it can be used to estimate how
+    /// selective a component is without scanning every archetype.
+    #[inline]
+    pub fn archetypes_with_component(&self, component_id: ComponentId) -> &[ArchetypeId] {
+        self.by_component
+            .get(&component_id)
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the number of entities with the component `component_id`.
+    ///
+    /// This sums the entity counts of the archetypes returned by
+    /// [`archetypes_with_component`](Self::archetypes_with_component).
+    pub fn component_entity_count(&self, component_id: ComponentId) -> usize {
+        self.archetypes_with_component(component_id)
+            .iter()
+            .map(|&id| self[id].len())
+            .sum()
+    }
+
     /// Gets the archetype id matching the given inputs or inserts a new one if it doesn't exist.
     /// `table_components` and `sparse_set_components` must be sorted
     ///
@@ -793,11 +817,15 @@ impl Archetypes {
 
         let archetypes = &mut self.archetypes;
         let archetype_component_count = &mut self.archetype_component_count;
+        let by_component = &mut self.by_component;
         *self
             .by_components
             .entry(archetype_identity)
             .or_insert_with(move || {
                 let id = ArchetypeId::new(archetypes.len());
+                for &component_id in table_components.iter().chain(&sparse_set_components) {
+                    by_component.entry(component_id).or_default().push(id);
+                }
                 let table_start = *archetype_component_count;
                 *archetype_component_count += table_components.len();
                 let table_archetype_components =
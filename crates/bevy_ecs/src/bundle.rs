@@ -129,6 +129,23 @@ use std::ptr::NonNull;
 /// struct PointName(String);
 /// ```
 ///
+/// A bundle cannot contain the same component more than once.
+/// The derive macro rejects bundles where several fields share the same type at compile time,
+/// while duplicates coming from nested bundles are reported with a panic when the bundle is first used.
+///
+/// ```compile_fail
+/// use bevy_ecs::{component::Component, bundle::Bundle};
+///
+/// #[derive(Component)]
+/// struct Health(u32);
+///
+/// #[derive(Bundle)]
+/// struct InvalidBundle {
+///     current: Health,
+///     max: Health,
+/// }
+/// ```
+///
 /// # Safety
 ///
 /// Manual implementations of this trait are unsupported.
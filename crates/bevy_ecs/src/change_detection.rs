@@ -1,7 +1,8 @@
 //! Types that detect when their internal data mutate.
 
 use crate::{
-    component::{Tick, TickCells},
+    component::{ComponentId, ComponentTicks, Tick, TickCells},
+    entity::Entity,
     ptr::PtrMut,
     system::Resource,
 };
@@ -43,6 +44,20 @@ pub const MAX_CHANGE_AGE: u32 = u32::MAX - (2 * CHECK_TICK_THRESHOLD - 1);
 ///     }
 /// }
 /// ```
+///
+/// ## Untracked values
+///
+/// Components with `#[component(change_detection = false)]` and resources with
+/// `#[resource(change_detection = false)]` do not record mutations:
+/// however they are accessed, they always report being changed, while [`is_added`](DetectChanges::is_added)
+/// and [`last_changed`](DetectChanges::last_changed) reflect their insertion.
+/// Such components cannot be used in change detection filters.
+///
+/// Generic code can check whether a type is tracked with [`World::is_change_tracked`],
+/// or [`World::is_change_tracked_by_id`] for dynamic components.
+///
+/// [`World::is_change_tracked`]: crate::world::World::is_change_tracked
+/// [`World::is_change_tracked_by_id`]: crate::world::World::is_change_tracked_by_id
 pub trait DetectChanges {
     /// Returns `true` if this value was added after the system last ran.
     fn is_added(&self) -> bool;
@@ -55,6 +70,18 @@ pub trait DetectChanges {
     /// use `this.is_changed() && !this.is_added()`.
     fn is_changed(&self) -> bool;
 
+    /// Returns `true` if this value was added after the given `tick`.
+    ///
+    /// Unlike [`is_added`](DetectChanges::is_added), this compares against a baseline tracked by
+    /// the caller rather than the last run of the system, which is useful for rollback or replication.
+    fn is_added_since(&self, tick: Tick) -> bool;
+
+    /// Returns `true` if this value was added or mutably dereferenced after the given `tick`.
+    ///
+    /// Unlike [`is_changed`](DetectChanges::is_changed), this compares against a baseline tracked by
+    /// the caller rather than the last run of the system, which is useful for rollback or replication.
+    fn is_changed_since(&self, tick: Tick) -> bool;
+
     /// Returns the change tick recording the time this data was most recently changed.
     ///
     /// Note that components and resources are also marked as changed upon insertion.
@@ -259,6 +286,49 @@ pub trait DetectChangesMut: DetectChanges {
     }
 }
 
+/// Whether a change detection smart pointer records change ticks when its value is mutated.
+///
+/// [`Res`] and [`ResMut`] resolve it at compile time from [`Resource::CHANGE_DETECTION_ENABLED`].
+/// Other pointers can be created for values of any type, so they read it from their ticks.
+trait TracksChanges {
+    fn tracks_changes(&self) -> bool;
+}
+
+impl<'w, T: ?Sized + Resource> TracksChanges for Res<'w, T> {
+    #[inline]
+    fn tracks_changes(&self) -> bool {
+        T::CHANGE_DETECTION_ENABLED
+    }
+}
+
+impl<'w, T: ?Sized + Resource> TracksChanges for ResMut<'w, T> {
+    #[inline]
+    fn tracks_changes(&self) -> bool {
+        T::CHANGE_DETECTION_ENABLED
+    }
+}
+
+impl<'w, T: ?Sized + 'static> TracksChanges for NonSendMut<'w, T> {
+    #[inline]
+    fn tracks_changes(&self) -> bool {
+        self.ticks.tracks_changes
+    }
+}
+
+impl<'w, T: ?Sized> TracksChanges for Ref<'w, T> {
+    #[inline]
+    fn tracks_changes(&self) -> bool {
+        self.ticks.tracks_changes
+    }
+}
+
+impl<'w, T: ?Sized> TracksChanges for Mut<'w, T> {
+    #[inline]
+    fn tracks_changes(&self) -> bool {
+        self.ticks.tracks_changes
+    }
+}
+
 macro_rules! change_detection_impl {
     ($name:ident < $( $generics:tt ),+ >, $target:ty, $($traits:ident)?) => {
         impl<$($generics),* : ?Sized $(+ $traits)?> DetectChanges for $name<$($generics),*> {
@@ -271,9 +341,23 @@ macro_rules! change_detection_impl {
 
             #[inline]
             fn is_changed(&self) -> bool {
-                self.ticks
-                    .changed
-                    .is_newer_than(self.ticks.last_run, self.ticks.this_run)
+                // Values without change detection can't prove they are unchanged.
+                !TracksChanges::tracks_changes(self)
+                    || self
+                        .ticks
+                        .changed
+                        .is_newer_than(self.ticks.last_run, self.ticks.this_run)
+            }
+
+            #[inline]
+            fn is_added_since(&self, tick: Tick) -> bool {
+                self.ticks.added.is_newer_than(tick, self.ticks.this_run)
+            }
+
+            #[inline]
+            fn is_changed_since(&self, tick: Tick) -> bool {
+                !TracksChanges::tracks_changes(self)
+                    || self.ticks.changed.is_newer_than(tick, self.ticks.this_run)
             }
 
             #[inline]
@@ -307,7 +391,9 @@ macro_rules! change_detection_mut_impl {
 
             #[inline]
             fn set_changed(&mut self) {
-                *self.ticks.changed = self.ticks.this_run;
+                if TracksChanges::tracks_changes(self) {
+                    *self.ticks.changed = self.ticks.this_run;
+                }
             }
 
             #[inline]
@@ -361,6 +447,7 @@ macro_rules! impl_methods {
                         changed: self.ticks.changed,
                         last_run: self.ticks.last_run,
                         this_run: self.ticks.this_run,
+                        tracks_changes: self.ticks.tracks_changes,
                     }
                 }
             }
@@ -427,6 +514,10 @@ pub(crate) struct Ticks<'w> {
     pub(crate) changed: &'w Tick,
     pub(crate) last_run: Tick,
     pub(crate) this_run: Tick,
+    /// `false` for components and resources with change detection disabled,
+    /// see [`Component::CHANGE_DETECTION_ENABLED`](crate::component::Component::CHANGE_DETECTION_ENABLED)
+    /// and [`Resource::CHANGE_DETECTION_ENABLED`].
+    pub(crate) tracks_changes: bool,
 }
 
 impl<'w> Ticks<'w> {
@@ -445,8 +536,17 @@ impl<'w> Ticks<'w> {
             changed: unsafe { cells.changed.deref() },
             last_run,
             this_run,
+            tracks_changes: true,
         }
     }
+
+    /// Sets whether these ticks belong to a value that records changes,
+    /// see [`Ticks::tracks_changes`].
+    #[inline]
+    pub(crate) fn with_change_detection(mut self, enabled: bool) -> Self {
+        self.tracks_changes = enabled;
+        self
+    }
 }
 
 pub(crate) struct TicksMut<'w> {
@@ -454,6 +554,10 @@ pub(crate) struct TicksMut<'w> {
     pub(crate) changed: &'w mut Tick,
     pub(crate) last_run: Tick,
     pub(crate) this_run: Tick,
+    /// `false` for components and resources with change detection disabled,
+    /// see [`Component::CHANGE_DETECTION_ENABLED`](crate::component::Component::CHANGE_DETECTION_ENABLED)
+    /// and [`Resource::CHANGE_DETECTION_ENABLED`].
+    pub(crate) tracks_changes: bool,
 }
 
 impl<'w> TicksMut<'w> {
@@ -472,8 +576,17 @@ impl<'w> TicksMut<'w> {
             changed: unsafe { cells.changed.deref_mut() },
             last_run,
             this_run,
+            tracks_changes: true,
         }
     }
+
+    /// Sets whether these ticks belong to a value that records changes,
+    /// see [`TicksMut::tracks_changes`].
+    #[inline]
+    pub(crate) fn with_change_detection(mut self, enabled: bool) -> Self {
+        self.tracks_changes = enabled;
+        self
+    }
 }
 
 impl<'w> From<TicksMut<'w>> for Ticks<'w> {
@@ -483,6 +596,7 @@ impl<'w> From<TicksMut<'w>> for Ticks<'w> {
             changed: ticks.changed,
             last_run: ticks.last_run,
             this_run: ticks.this_run,
+            tracks_changes: ticks.tracks_changes,
         }
     }
 }
@@ -708,6 +822,7 @@ impl<'w, T: ?Sized> Ref<'w, T> {
                 changed,
                 last_run,
                 this_run,
+                tracks_changes: true,
             },
         }
     }
@@ -763,6 +878,7 @@ impl<'w, T: ?Sized> Mut<'w, T> {
                 changed: last_changed,
                 last_run,
                 this_run,
+                tracks_changes: true,
             },
         }
     }
@@ -841,6 +957,7 @@ impl<'w> MutUntyped<'w> {
                 changed: self.ticks.changed,
                 last_run: self.ticks.last_run,
                 this_run: self.ticks.this_run,
+                tracks_changes: self.ticks.tracks_changes,
             },
         }
     }
@@ -918,9 +1035,21 @@ impl<'w> DetectChanges for MutUntyped<'w> {
 
     #[inline]
     fn is_changed(&self) -> bool {
-        self.ticks
-            .changed
-            .is_newer_than(self.ticks.last_run, self.ticks.this_run)
+        !self.ticks.tracks_changes
+            || self
+                .ticks
+                .changed
+                .is_newer_than(self.ticks.last_run, self.ticks.this_run)
+    }
+
+    #[inline]
+    fn is_added_since(&self, tick: Tick) -> bool {
+        self.ticks.added.is_newer_than(tick, self.ticks.this_run)
+    }
+
+    #[inline]
+    fn is_changed_since(&self, tick: Tick) -> bool {
+        !self.ticks.tracks_changes || self.ticks.changed.is_newer_than(tick, self.ticks.this_run)
     }
 
     #[inline]
@@ -934,7 +1063,9 @@ impl<'w> DetectChangesMut for MutUntyped<'w> {
 
     #[inline]
     fn set_changed(&mut self) {
-        *self.ticks.changed = self.ticks.this_run;
+        if self.ticks.tracks_changes {
+            *self.ticks.changed = self.ticks.this_run;
+        }
     }
 
     #[inline]
@@ -965,6 +1096,63 @@ impl<'w, T> From<Mut<'w, T>> for MutUntyped<'w> {
     }
 }
 
+/// The change ticks of a set of components, captured by [`World::snapshot_change_ticks`].
+///
+/// Ticks are stored as ages relative to the world's change tick at the time of the snapshot.
+/// Restoring them with [`World::restore_change_ticks`] remaps them onto the current change tick,
+/// so deterministic rollback keeps change detection consistent however far the world has advanced.
+///
+/// [`World::snapshot_change_ticks`]: crate::world::World::snapshot_change_ticks
+/// [`World::restore_change_ticks`]: crate::world::World::restore_change_ticks
+#[derive(Debug, Clone, Default)]
+pub struct ChangeTicksSnapshot {
+    pub(crate) entries: Vec<ChangeTicksSnapshotEntry>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ChangeTicksSnapshotEntry {
+    pub(crate) entity: Entity,
+    pub(crate) component_id: ComponentId,
+    pub(crate) added_age: u32,
+    pub(crate) changed_age: u32,
+}
+
+impl ChangeTicksSnapshot {
+    /// Returns the number of component ticks stored in this snapshot.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if this snapshot stores no component ticks.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Records the `ticks` of `component_id` on `entity`, relative to `change_tick`.
+    pub(crate) fn push(
+        &mut self,
+        entity: Entity,
+        component_id: ComponentId,
+        ticks: ComponentTicks,
+        change_tick: Tick,
+    ) {
+        self.entries.push(ChangeTicksSnapshotEntry {
+            entity,
+            component_id,
+            added_age: change_tick
+                .relative_to(ticks.added)
+                .get()
+                .min(MAX_CHANGE_AGE),
+            changed_age: change_tick
+                .relative_to(ticks.changed)
+                .get()
+                .min(MAX_CHANGE_AGE),
+        });
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_ecs_macros::Resource;
@@ -993,6 +1181,14 @@ mod tests {
     #[derive(Resource, PartialEq)]
     struct R2(u8);
 
+    #[derive(Resource)]
+    #[resource(change_detection = false)]
+    struct UntrackedR(u8);
+
+    #[derive(Component)]
+    #[component(change_detection = false)]
+    struct UntrackedC(u8);
+
     impl Deref for R2 {
         type Target = u8;
         fn deref(&self) -> &u8 {
@@ -1058,6 +1254,101 @@ mod tests {
         assert!(query.single(&world).is_changed());
     }
 
+    #[test]
+    fn changed_since_tick() {
+        let mut world = World::new();
+
+        let before_spawn = world.increment_change_tick();
+        let entity = world.spawn(C).id();
+        world.increment_change_tick();
+        let baseline = world.change_tick();
+        world.increment_change_tick();
+        world.get_mut::<C>(entity).unwrap().set_changed();
+
+        let c = world.entity(entity).get_ref::<C>().unwrap();
+        assert!(c.is_added_since(before_spawn));
+        assert!(!c.is_added_since(baseline));
+        assert!(c.is_changed_since(baseline));
+        assert!(!c.is_changed_since(world.read_change_tick()));
+    }
+
+    #[test]
+    fn untracked_component_skips_change_ticks() {
+        let mut world = World::new();
+        let entity = world.spawn(UntrackedC(0)).id();
+        let inserted = world
+            .entity(entity)
+            .get_ref::<UntrackedC>()
+            .unwrap()
+            .last_changed();
+        world.increment_change_tick();
+
+        let mut component = world.get_mut::<UntrackedC>(entity).unwrap();
+        component.0 = 1;
+        // Without change detection, components always report being changed.
+        assert!(component.is_changed());
+        assert_eq!(component.last_changed(), inserted);
+
+        let mut query = world.query::<&mut UntrackedC>();
+        let mut component = query.single_mut(&mut world);
+        component.0 = 2;
+        assert!(component.is_changed());
+        assert_eq!(component.last_changed(), inserted);
+
+        let component_id = world.component_id::<UntrackedC>().unwrap();
+        let mut component = world
+            .entity_mut(entity)
+            .into_mut_by_id(component_id)
+            .unwrap();
+        component.set_changed();
+        assert_eq!(component.last_changed(), inserted);
+    }
+
+    #[test]
+    fn untracked_resource_skips_change_ticks() {
+        let mut world = World::new();
+        world.insert_resource(UntrackedR(0));
+        let inserted = world.resource_ref::<UntrackedR>().last_changed();
+        world.increment_change_tick();
+
+        let mut system = IntoSystem::into_system(|mut res: ResMut<UntrackedR>| {
+            res.0 = 1;
+            // Without change detection, resources always report being changed.
+            assert!(res.is_changed());
+        });
+        system.initialize(&mut world);
+        system.run((), &mut world);
+
+        let res = world.resource_ref::<UntrackedR>();
+        assert_eq!(res.0, 1);
+        assert_eq!(res.last_changed(), inserted);
+        assert!(res.is_changed());
+    }
+
+    #[test]
+    fn untracked_resource_skips_change_ticks_on_world_access() {
+        let mut world = World::new();
+        world.insert_resource(UntrackedR(0));
+        let inserted = world.resource_ref::<UntrackedR>().last_changed();
+        world.increment_change_tick();
+
+        let mut res = world.resource_mut::<UntrackedR>();
+        res.0 = 1;
+        assert!(res.is_changed());
+        assert_eq!(res.last_changed(), inserted);
+
+        let component_id = world.components().resource_id::<UntrackedR>().unwrap();
+        let mut res = world.get_resource_mut_by_id(component_id).unwrap();
+        res.set_changed();
+        assert!(res.is_changed());
+        assert_eq!(res.last_changed(), inserted);
+
+        world.clear_trackers();
+        assert!(world.is_resource_changed::<UntrackedR>());
+        assert!(world.resource_ref::<UntrackedR>().is_changed());
+        assert!(world.resource_mut::<UntrackedR>().is_changed());
+    }
+
     #[test]
     fn change_tick_scan() {
         let mut world = World::new();
@@ -1079,6 +1370,7 @@ mod tests {
 
         // scan change ticks and clamp those at risk of overflow
         world.check_change_ticks();
+        assert_eq!(world.last_check_tick(), change_tick);
 
         for tracker in query.iter(&world) {
             let ticks_since_insert = change_tick.relative_to(*tracker.ticks.added).get();
@@ -1099,6 +1391,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
+            tracks_changes: true,
         };
         let mut res = R {};
         let res_mut = ResMut {
@@ -1144,6 +1437,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
+            tracks_changes: true,
         };
         let mut res = R {};
         let non_send_mut = NonSendMut {
@@ -1174,6 +1468,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run,
             this_run,
+            tracks_changes: true,
         };
 
         let mut outer = Outer(0);
@@ -1259,6 +1554,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run,
             this_run,
+            tracks_changes: true,
         };
 
         let mut value: i32 = 5;
@@ -1293,6 +1589,7 @@ mod tests {
             changed: &mut component_ticks.changed,
             last_run: Tick::new(3),
             this_run: Tick::new(4),
+            tracks_changes: true,
         };
         let mut c = C {};
         let mut_typed = Mut {
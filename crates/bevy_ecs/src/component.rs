@@ -10,7 +10,7 @@ use crate::{
     world::{DeferredWorld, FromWorld, World},
 };
 pub use bevy_ecs_macros::Component;
-use bevy_ptr::{OwningPtr, UnsafeCellDeref};
+use bevy_ptr::{OwningPtr, Ptr, UnsafeCellDeref};
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
 use bevy_utils::TypeIdMap;
@@ -21,6 +21,7 @@ use std::{
     borrow::Cow,
     marker::PhantomData,
     mem::needs_drop,
+    sync::Arc,
 };
 
 /// A data type that can be used to store data for an [entity].
@@ -90,9 +91,45 @@ use std::{
 /// struct ComponentA;
 /// ```
 ///
+/// The storage type can also be made conditional on a `cfg` predicate with the `if` attribute.
+/// When the predicate does not hold, the component falls back to the default [`Table`] storage.
+/// This is useful for components that are added and removed constantly in development builds,
+/// but rarely in shipping builds:
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// #
+/// #[derive(Component)]
+/// #[component(storage = "SparseSet", if = debug_assertions)]
+/// struct Selected;
+/// ```
+///
+/// Any predicate accepted by `cfg` can be used, such as `if = feature = "editor"` or `if = any(test, debug_assertions)`.
+///
 /// [`Table`]: crate::storage::Table
 /// [`SparseSet`]: crate::storage::SparseSet
 ///
+/// # Disabling change detection
+///
+/// Components that are mutated every frame, or whose changes are never observed, can opt out of change detection
+/// with the `#[component(change_detection = false)]` attribute.
+/// Mutating them through [`Mut`] skips the change tick write, and [`Ref`] and [`Mut`] always report them as changed.
+/// Since their ticks are only written on insertion, they cannot be used in [`Added`] or [`Changed`] filters:
+/// doing so is rejected at compile time.
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// #
+/// #[derive(Component)]
+/// #[component(change_detection = false)]
+/// struct Velocity(f32);
+/// ```
+///
+/// [`Added`]: crate::query::Added
+/// [`Changed`]: crate::query::Changed
+/// [`Ref`]: crate::change_detection::Ref
+/// [`Mut`]: crate::change_detection::Mut
+///
 /// # Implementing the trait for foreign types
 ///
 /// As a consequence of the [orphan rule], it is not possible to separate into two different crates the implementation of `Component` from the definition of a type.
@@ -154,6 +191,34 @@ pub trait Component: Send + Sync + 'static {
     /// A constant indicating the storage type used for this component.
     const STORAGE_TYPE: StorageType;
 
+    /// A constant indicating whether mutations of this component record change ticks.
+    ///
+    /// Mutating a component with change detection disabled through a [`Mut`](crate::change_detection::Mut)
+    /// skips the change tick write, and [`DetectChanges`](crate::change_detection::DetectChanges)
+    /// always reports it as changed. Its ticks are only written on insertion, so it cannot be used
+    /// in [`Added`] or [`Changed`] filters.
+    /// This is configured via the derive attribute `#[component(change_detection = false)]`.
+    ///
+    /// [`Added`]: crate::query::Added
+    /// [`Changed`]: crate::query::Changed
+    const CHANGE_DETECTION_ENABLED: bool = true;
+
+    /// A constructor for a default value of this component.
+    ///
+    /// When set, a type-erased [`ComponentDefaultFn`] calling it is stored in the [`ComponentInfo`],
+    /// allowing dynamic tooling to create this component from its [`ComponentId`] alone.
+    /// It is set via the derive attribute `#[component(register_default)]` for types implementing [`Default`].
+    const DEFAULT_CONSTRUCTOR: Option<fn() -> Self> = None;
+
+    /// A function cloning this component as it is removed.
+    ///
+    /// When set, a type-erased [`ComponentRemovedValueFn`] calling it is stored in the [`ComponentInfo`],
+    /// and removal events sent to [`RemovedComponents`] carry a copy of the removed value.
+    /// It is set via the derive attribute `#[component(removed_value)]` for types implementing [`Clone`].
+    ///
+    /// [`RemovedComponents`]: crate::removal_detection::RemovedComponents
+    const REMOVED_VALUE: Option<fn(&Self) -> Self> = None;
+
     /// Called when registering this component, allowing mutable access to its [`ComponentHooks`].
     fn register_component_hooks(_hooks: &mut ComponentHooks) {}
 }
@@ -179,6 +244,20 @@ pub enum StorageType {
     SparseSet,
 }
 
+/// A type-erased constructor for the default value of a [`Component`].
+///
+/// The constructed value is passed to the provided callback, which must take ownership of it.
+/// See [`Component::DEFAULT_CONSTRUCTOR`] and [`ComponentInfo::default_constructor`].
+pub type ComponentDefaultFn = fn(&mut dyn FnMut(OwningPtr<'_>));
+
+/// A type-erased function cloning a [`Component`] that is being removed from an entity.
+///
+/// See [`Component::REMOVED_VALUE`] and [`ComponentInfo::removed_value`].
+///
+/// # Safety
+/// The pointer must point to a valid value of the component type this function was registered for.
+pub type ComponentRemovedValueFn = unsafe fn(Ptr<'_>) -> Arc<dyn Any + Send + Sync>;
+
 /// The type used for [`Component`] lifecycle hooks such as `on_add`, `on_insert` or `on_remove`
 pub type ComponentHook = for<'w> fn(DeferredWorld<'w>, Entity, ComponentId);
 
@@ -310,6 +389,30 @@ impl ComponentInfo {
         self.descriptor.is_send_and_sync
     }
 
+    /// Returns the type-erased constructor for a default value of the current component, if one was registered.
+    ///
+    /// See [`Component::DEFAULT_CONSTRUCTOR`] for more information.
+    #[inline]
+    pub fn default_constructor(&self) -> Option<ComponentDefaultFn> {
+        self.descriptor.default_constructor
+    }
+
+    /// Returns the type-erased function cloning the current component as it is removed, if one was registered.
+    ///
+    /// See [`Component::REMOVED_VALUE`] for more information.
+    #[inline]
+    pub fn removed_value(&self) -> Option<ComponentRemovedValueFn> {
+        self.descriptor.removed_value
+    }
+
+    /// Returns `true` if change detection is enabled for the current component or resource.
+    ///
+    /// See [`Component::CHANGE_DETECTION_ENABLED`] and [`Resource::CHANGE_DETECTION_ENABLED`] for more information.
+    #[inline]
+    pub fn change_detection_enabled(&self) -> bool {
+        self.descriptor.change_detection_enabled
+    }
+
     /// Create a new [`ComponentInfo`].
     pub(crate) fn new(id: ComponentId, descriptor: ComponentDescriptor) -> Self {
         ComponentInfo {
@@ -407,12 +510,17 @@ pub struct ComponentDescriptor {
     // SAFETY: This must remain private. It must only be set to "true" if this component is
     // actually Send + Sync
     is_send_and_sync: bool,
+    change_detection_enabled: bool,
     type_id: Option<TypeId>,
     layout: Layout,
     // SAFETY: this function must be safe to call with pointers pointing to items of the type
     // this descriptor describes.
     // None if the underlying type doesn't need to be dropped
     drop: Option<for<'a> unsafe fn(OwningPtr<'a>)>,
+    // SAFETY: this function must only pass pointers to items of the type this descriptor describes.
+    default_constructor: Option<ComponentDefaultFn>,
+    // SAFETY: this function must only be called with pointers to items of the type this descriptor describes.
+    removed_value: Option<ComponentRemovedValueFn>,
 }
 
 // We need to ignore the `drop` field in our `Debug` impl
@@ -422,6 +530,7 @@ impl std::fmt::Debug for ComponentDescriptor {
             .field("name", &self.name)
             .field("storage_type", &self.storage_type)
             .field("is_send_and_sync", &self.is_send_and_sync)
+            .field("change_detection_enabled", &self.change_detection_enabled)
             .field("type_id", &self.type_id)
             .field("layout", &self.layout)
             .finish()
@@ -439,15 +548,41 @@ impl ComponentDescriptor {
         }
     }
 
+    /// Only registered for components with a [`Component::DEFAULT_CONSTRUCTOR`].
+    fn default_ptr<T: Component>(f: &mut dyn FnMut(OwningPtr<'_>)) {
+        if let Some(constructor) = T::DEFAULT_CONSTRUCTOR {
+            OwningPtr::make(constructor(), f);
+        }
+    }
+
+    /// Only registered for components with a [`Component::REMOVED_VALUE`].
+    ///
+    /// # Safety
+    ///
+    /// `x` must point to a valid value of type `T`.
+    unsafe fn clone_removed_ptr<T: Component>(x: Ptr<'_>) -> Arc<dyn Any + Send + Sync> {
+        let clone = T::REMOVED_VALUE
+            .expect("only registered for components with a `Component::REMOVED_VALUE`");
+        // SAFETY: Contract is required to be upheld by the caller.
+        Arc::new(clone(unsafe { x.deref::<T>() }))
+    }
+
     /// Create a new `ComponentDescriptor` for the type `T`.
     pub fn new<T: Component>() -> Self {
         Self {
             name: Cow::Borrowed(std::any::type_name::<T>()),
             storage_type: T::STORAGE_TYPE,
             is_send_and_sync: true,
+            change_detection_enabled: T::CHANGE_DETECTION_ENABLED,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            default_constructor: T::DEFAULT_CONSTRUCTOR
+                .is_some()
+                .then_some(Self::default_ptr::<T> as _),
+            removed_value: T::REMOVED_VALUE
+                .is_some()
+                .then_some(Self::clone_removed_ptr::<T> as _),
         }
     }
 
@@ -466,9 +601,12 @@ impl ComponentDescriptor {
             name: name.into(),
             storage_type,
             is_send_and_sync: true,
+            change_detection_enabled: true,
             type_id: None,
             layout,
             drop,
+            default_constructor: None,
+            removed_value: None,
         }
     }
 
@@ -482,9 +620,12 @@ impl ComponentDescriptor {
             // reasonable choice as `storage_type` for resources.
             storage_type: StorageType::Table,
             is_send_and_sync: true,
+            change_detection_enabled: T::CHANGE_DETECTION_ENABLED,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            default_constructor: None,
+            removed_value: None,
         }
     }
 
@@ -493,9 +634,12 @@ impl ComponentDescriptor {
             name: Cow::Borrowed(std::any::type_name::<T>()),
             storage_type,
             is_send_and_sync: false,
+            change_detection_enabled: true,
             type_id: Some(TypeId::of::<T>()),
             layout: Layout::new::<T>(),
             drop: needs_drop::<T>().then_some(Self::drop_ptr::<T> as _),
+            default_constructor: None,
+            removed_value: None,
         }
     }
 
@@ -512,6 +656,14 @@ impl ComponentDescriptor {
         self.type_id
     }
 
+    /// Returns `true` if change detection is enabled for the component or resource.
+    ///
+    /// See [`ComponentInfo::change_detection_enabled`].
+    #[inline]
+    pub fn change_detection_enabled(&self) -> bool {
+        self.change_detection_enabled
+    }
+
     /// Returns the name of the current component.
     #[inline]
     pub fn name(&self) -> &str {
@@ -820,6 +972,15 @@ impl Tick {
         Self { tick }
     }
 
+    /// Returns the oldest change tick still tracked at `now`.
+    ///
+    /// Ticks at least [`Tick::MAX`] old are clamped to this value, so it never compares as newer
+    /// than a system's last run. Assigning it to a component marks it as neither added nor changed.
+    #[inline]
+    pub(crate) fn expired(now: Tick) -> Self {
+        now.relative_to(Self::MAX)
+    }
+
     /// Wraps this change tick's value if it exceeds [`Tick::MAX`].
     ///
     /// Returns `true` if wrapping was performed. Otherwise, returns `false`.
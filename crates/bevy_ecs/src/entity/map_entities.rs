@@ -5,6 +5,7 @@ use crate::{
 };
 
 use super::EntityHashMap;
+pub use bevy_ecs_macros::MapEntities;
 
 /// Operation to map all contained [`Entity`] fields in a type to new values.
 ///
@@ -38,6 +39,38 @@ use super::EntityHashMap;
 /// }
 /// ```
 ///
+/// ## Deriving `MapEntities`
+///
+/// This trait can also be derived for components, resources and events alike.
+/// Fields of type [`Entity`] are mapped automatically, and any other field implementing
+/// `MapEntities` can be mapped by marking it with `#[entities]`:
+///
+/// ```
+/// use bevy_ecs::prelude::*;
+/// use bevy_ecs::entity::MapEntities;
+///
+/// #[derive(Component, MapEntities)]
+/// struct Squad {
+///     leader: Entity,
+///     #[entities]
+///     members: Vec<Entity>,
+///     name: String,
+/// }
+///
+/// #[derive(Event, MapEntities)]
+/// enum SquadEvent {
+///     Joined(Entity),
+///     Disbanded,
+/// }
+/// ```
+///
+/// To register the type data used to map entities when loading scenes, reflect `MapEntities` for components
+/// ([`ReflectMapEntities`]), `MapEntitiesResource` for resources ([`ReflectMapEntitiesResource`])
+/// or `MapEntitiesEvent` for events ([`ReflectMapEntitiesEvent`]).
+///
+/// [`ReflectMapEntities`]: crate::reflect::ReflectMapEntities
+/// [`ReflectMapEntitiesResource`]: crate::reflect::ReflectMapEntitiesResource
+/// [`ReflectMapEntitiesEvent`]: crate::reflect::ReflectMapEntitiesEvent
 pub trait MapEntities {
     /// Updates all [`Entity`] references stored inside using `entity_mapper`.
     ///
@@ -46,6 +79,28 @@ pub trait MapEntities {
     fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M);
 }
 
+impl MapEntities for Entity {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        *self = entity_mapper.map_entity(*self);
+    }
+}
+
+impl<T: MapEntities> MapEntities for Option<T> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        if let Some(value) = self {
+            value.map_entities(entity_mapper);
+        }
+    }
+}
+
+impl<T: MapEntities> MapEntities for Vec<T> {
+    fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+        for value in self {
+            value.map_entities(entity_mapper);
+        }
+    }
+}
+
 /// An implementor of this trait knows how to map an [`Entity`] into another [`Entity`].
 ///
 /// Usually this is done by using an [`EntityHashMap<Entity>`] to map source entities
@@ -173,10 +228,68 @@ impl<'m> SceneEntityMapper<'m> {
 #[cfg(test)]
 mod tests {
     use crate::{
-        entity::{Entity, EntityHashMap, EntityMapper, SceneEntityMapper},
+        self as bevy_ecs,
+        entity::{Entity, EntityHashMap, EntityMapper, MapEntities, SceneEntityMapper},
         world::World,
     };
 
+    #[derive(MapEntities)]
+    struct Named {
+        target: Entity,
+        #[entities]
+        others: Vec<Entity>,
+        #[entities]
+        maybe: Option<Entity>,
+        unmapped: u32,
+    }
+
+    #[derive(MapEntities)]
+    enum Variants {
+        Tuple(u32, Entity),
+        Named { target: Entity },
+        Unit,
+    }
+
+    struct OffsetMapper;
+
+    impl EntityMapper for OffsetMapper {
+        fn map_entity(&mut self, entity: Entity) -> Entity {
+            Entity::from_raw(entity.index() + 100)
+        }
+    }
+
+    #[test]
+    fn derive_map_entities() {
+        let mut named = Named {
+            target: Entity::from_raw(1),
+            others: vec![Entity::from_raw(2), Entity::from_raw(3)],
+            maybe: Some(Entity::from_raw(4)),
+            unmapped: 5,
+        };
+        named.map_entities(&mut OffsetMapper);
+        assert_eq!(named.target, Entity::from_raw(101));
+        assert_eq!(
+            named.others,
+            vec![Entity::from_raw(102), Entity::from_raw(103)]
+        );
+        assert_eq!(named.maybe, Some(Entity::from_raw(104)));
+        assert_eq!(named.unmapped, 5);
+
+        let mut tuple = Variants::Tuple(1, Entity::from_raw(1));
+        tuple.map_entities(&mut OffsetMapper);
+        assert!(matches!(tuple, Variants::Tuple(1, e) if e == Entity::from_raw(101)));
+
+        let mut named = Variants::Named {
+            target: Entity::from_raw(2),
+        };
+        named.map_entities(&mut OffsetMapper);
+        assert!(matches!(named, Variants::Named { target } if target == Entity::from_raw(102)));
+
+        let mut unit = Variants::Unit;
+        unit.map_entities(&mut OffsetMapper);
+        assert!(matches!(unit, Variants::Unit));
+    }
+
     #[test]
     fn entity_mapper() {
         const FIRST_IDX: u32 = 1;
@@ -323,6 +323,14 @@ impl<E: Event> Events<E> {
             .map(|i| i.event)
     }
 
+    /// Mutably iterates over all stored events, oldest first.
+    pub(crate) fn iter_mut(&mut self) -> impl Iterator<Item = &mut E> {
+        self.events_a
+            .iter_mut()
+            .chain(self.events_b.iter_mut())
+            .map(|i| &mut i.event)
+    }
+
     /// Iterates over events that happened since the last "update" call.
     /// WARNING: You probably don't want to use this call. In most cases you should use an
     /// [`EventReader`]. You should only use this if you know you only need to consume events
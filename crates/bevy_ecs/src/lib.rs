@@ -70,7 +70,7 @@ mod tests {
         change_detection::Ref,
         component::{Component, ComponentId},
         entity::Entity,
-        query::{Added, Changed, FilteredAccess, QueryFilter, With, Without},
+        query::{Added, Changed, FilteredAccess, Or, QueryFilter, With, Without},
         system::Resource,
         world::{EntityRef, Mut, World},
     };
@@ -123,6 +123,12 @@ mod tests {
     #[derive(Component, Copy, Clone, PartialEq, Eq, Debug)]
     #[component(storage = "SparseSet")]
     struct SparseStored(u32);
+    #[derive(Component)]
+    #[component(storage = "SparseSet", if = test)]
+    struct ConditionallySparse;
+    #[derive(Component)]
+    #[component(storage = "SparseSet", if = not(test))]
+    struct ConditionallyTable;
 
     #[test]
     fn random_access() {
@@ -293,6 +299,75 @@ mod tests {
                 ignored: Ignored,
             }
         );
+
+        #[derive(Bundle, PartialEq, Debug)]
+        struct TupleBundleWithIgnored(
+            #[bundle(ignore)] Ignored,
+            #[bundle(ignore)] usize,
+            B,
+            FooBundle,
+        );
+
+        let mut ids = Vec::new();
+        <TupleBundleWithIgnored as Bundle>::component_ids(
+            &mut world.components,
+            &mut world.storages,
+            &mut |id| {
+                ids.push(id);
+            },
+        );
+
+        assert_eq!(
+            ids,
+            &[
+                world.init_component::<B>(),
+                world.init_component::<TableStored>(),
+                world.init_component::<SparseStored>(),
+            ]
+        );
+
+        let e5 = world
+            .spawn(TupleBundleWithIgnored(
+                Ignored,
+                7,
+                B(3),
+                FooBundle {
+                    x: TableStored("jkl"),
+                    y: SparseStored(1),
+                },
+            ))
+            .id();
+
+        assert_eq!(world.get::<B>(e5).unwrap(), &B(3));
+        assert_eq!(world.get::<Ignored>(e5), None);
+        assert_eq!(
+            world
+                .entity_mut(e5)
+                .take::<TupleBundleWithIgnored>()
+                .unwrap(),
+            TupleBundleWithIgnored(
+                Ignored,
+                0,
+                B(3),
+                FooBundle {
+                    x: TableStored("jkl"),
+                    y: SparseStored(1),
+                },
+            )
+        );
+    }
+
+    #[test]
+    fn conditional_storage_type() {
+        use crate::component::StorageType;
+
+        assert_eq!(ConditionallySparse::STORAGE_TYPE, StorageType::SparseSet);
+        assert_eq!(ConditionallyTable::STORAGE_TYPE, StorageType::Table);
+
+        let mut world = World::new();
+        let e = world.spawn((ConditionallySparse, ConditionallyTable)).id();
+        assert!(world.entity(e).contains::<ConditionallySparse>());
+        assert!(world.entity(e).contains::<ConditionallyTable>());
     }
 
     #[test]
@@ -1064,6 +1139,64 @@ mod tests {
         assert_eq!(get_filtered::<Changed<SparseStored>>(&mut world), vec![e4]);
     }
 
+    #[test]
+    fn changed_trackers_mixed_storage() {
+        let mut world = World::default();
+        let e1 = world.spawn((TableStored("a"), SparseStored(0))).id();
+        let e2 = world.spawn((TableStored("b"), SparseStored(0))).id();
+        let e3 = world.spawn((TableStored("c"), SparseStored(0))).id();
+
+        world.clear_trackers();
+
+        world.get_mut::<TableStored>(e1).unwrap().0 = "d";
+        world.get_mut::<SparseStored>(e2).unwrap().0 = 1;
+        world.get_mut::<TableStored>(e3).unwrap().0 = "e";
+        world.get_mut::<SparseStored>(e3).unwrap().0 = 1;
+
+        fn get_filtered<F: QueryFilter>(world: &mut World) -> Vec<Entity> {
+            world
+                .query_filtered::<Entity, F>()
+                .iter(world)
+                .collect::<Vec<Entity>>()
+        }
+
+        assert_eq!(
+            get_filtered::<Changed<TableStored>>(&mut world),
+            vec![e1, e3]
+        );
+        assert_eq!(
+            get_filtered::<Changed<SparseStored>>(&mut world),
+            vec![e2, e3]
+        );
+        assert_eq!(
+            get_filtered::<(Changed<TableStored>, Changed<SparseStored>)>(&mut world),
+            vec![e3]
+        );
+        assert_eq!(
+            get_filtered::<Or<(Changed<TableStored>, Changed<SparseStored>)>>(&mut world),
+            vec![e1, e2, e3]
+        );
+
+        // moving to a new archetype must carry both dense and sparse ticks along
+        world.entity_mut(e2).insert(A(0));
+        assert_eq!(
+            get_filtered::<(Changed<SparseStored>, Without<A>)>(&mut world),
+            vec![e3]
+        );
+        assert_eq!(
+            get_filtered::<(Changed<SparseStored>, With<A>)>(&mut world),
+            vec![e2]
+        );
+        assert!(get_filtered::<(Changed<TableStored>, With<A>)>(&mut world).is_empty());
+
+        world.clear_trackers();
+
+        assert!(
+            get_filtered::<Or<(Changed<TableStored>, Changed<SparseStored>)>>(&mut world)
+                .is_empty()
+        );
+    }
+
     #[test]
     fn empty_spawn() {
         let mut world = World::default();
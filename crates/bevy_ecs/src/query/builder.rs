@@ -2,7 +2,7 @@ use std::marker::PhantomData;
 
 use crate::{component::ComponentId, prelude::*};
 
-use super::{FilteredAccess, QueryData, QueryFilter};
+use super::{FilteredAccess, QueryData, QueryFilter, QueryNotRegisteredError};
 
 /// Builder struct to create [`QueryState`] instances at runtime.
 ///
@@ -111,6 +111,46 @@ impl<'w, D: QueryData, F: QueryFilter> QueryBuilder<'w, D, F> {
         self
     }
 
+    /// Adds accesses required for `T` to self, without registering any of its components.
+    ///
+    /// Unlike [`data`](Self::data), this doesn't modify the world, which makes it suitable for
+    /// builders that are only used to inspect [`access`](Self::access) and then discarded.
+    ///
+    /// Returns an error, leaving self unchanged, if any component used by `T` is not registered yet.
+    pub fn try_data<T: QueryData>(&mut self) -> Result<&mut Self, QueryNotRegisteredError> {
+        let state =
+            T::get_state(self.world).ok_or(QueryNotRegisteredError(std::any::type_name::<T>()))?;
+        let mut access = FilteredAccess::default();
+        T::update_component_access(&state, &mut access);
+        self.extend_access(access);
+        Ok(self)
+    }
+
+    /// Adds filter from `T` to self, without registering any of its components.
+    ///
+    /// See [`try_data`](Self::try_data) for details. As with [`filter`](Self::filter),
+    /// [`Added`](crate::query::Added) and [`Changed`](crate::query::Changed) filters on a component
+    /// without change detection are rejected at compile time:
+    ///
+    /// ```compile_fail
+    /// # use bevy_ecs::{prelude::*, query::QueryBuilder};
+    /// #[derive(Component)]
+    /// #[component(change_detection = false)]
+    /// struct Velocity(f32);
+    ///
+    /// let mut world = World::new();
+    /// world.init_component::<Velocity>();
+    /// let _ = QueryBuilder::<Entity>::new(&mut world).try_filter::<Changed<Velocity>>();
+    /// ```
+    pub fn try_filter<T: QueryFilter>(&mut self) -> Result<&mut Self, QueryNotRegisteredError> {
+        let state =
+            T::get_state(self.world).ok_or(QueryNotRegisteredError(std::any::type_name::<T>()))?;
+        let mut access = FilteredAccess::default();
+        T::update_component_access(&state, &mut access);
+        self.extend_access(access);
+        Ok(self)
+    }
+
     /// Adds [`With<T>`] to the [`FilteredAccess`] of self.
     pub fn with<T: Component>(&mut self) -> &mut Self {
         self.filter::<With<T>>();
@@ -245,6 +285,20 @@ impl<'w, D: QueryData, F: QueryFilter> QueryBuilder<'w, D, F> {
     pub fn build(&mut self) -> QueryState<D, F> {
         QueryState::<D, F>::from_builder(self)
     }
+
+    /// Create a [`QueryState`] with the accesses of the builder, without matching it
+    /// against the world's archetypes yet.
+    ///
+    /// Unlike [`build`](Self::build), which scans every existing archetype up front, the
+    /// archetypes are matched the first time the state is used through a method that calls
+    /// [`QueryState::update_archetypes`], such as [`QueryState::iter`] or [`QueryState::get`].
+    /// This avoids the cost for queries that end up being used rarely or not at all.
+    ///
+    /// Methods that don't update archetypes, like [`QueryState::iter_manual`], will not return
+    /// any results until [`QueryState::update_archetypes`] has been called.
+    pub fn build_lazy(&mut self) -> QueryState<D, F> {
+        QueryState::<D, F>::from_builder_uninitialized(self)
+    }
 }
 
 #[cfg(test)]
@@ -281,6 +335,42 @@ mod tests {
         assert_eq!(entity_b, query_b.single(&world));
     }
 
+    #[test]
+    fn builder_lazy() {
+        let mut world = World::new();
+        let entity = world.spawn((A(0), B(0))).id();
+        world.spawn(A(1));
+
+        let mut query = QueryBuilder::<Entity>::new(&mut world)
+            .with::<B>()
+            .build_lazy();
+        assert_eq!(0, query.matched_archetypes().count());
+        assert_eq!(0, query.iter_manual(&world).count());
+
+        assert_eq!(entity, query.single(&world));
+        assert_eq!(1, query.matched_archetypes().count());
+    }
+
+    #[test]
+    fn builder_try_data_does_not_register() {
+        let mut world = World::new();
+        world.init_component::<A>();
+
+        let mut builder = QueryBuilder::<()>::new(&mut world);
+        assert!(builder.try_data::<&A>().is_ok());
+        assert!(builder.try_filter::<With<A>>().is_ok());
+        assert!(builder.try_data::<&B>().is_err());
+        assert!(builder.try_filter::<Without<C>>().is_err());
+
+        let a = world.component_id::<A>().unwrap();
+        assert!(world.component_id::<B>().is_none());
+        assert!(world.component_id::<C>().is_none());
+
+        let mut builder = QueryBuilder::<()>::new(&mut world);
+        builder.try_data::<&A>().unwrap();
+        assert!(builder.access().access().has_read(a));
+    }
+
     #[test]
     fn builder_with_without_dynamic() {
         let mut world = World::new();
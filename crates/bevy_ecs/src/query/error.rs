@@ -32,3 +32,10 @@ pub enum QuerySingleError {
     #[error("Multiple entities fit the query {0}")]
     MultipleEntities(&'static str),
 }
+
+/// An error that occurs when [`QueryBuilder::try_data`](crate::query::QueryBuilder::try_data) or
+/// [`QueryBuilder::try_filter`](crate::query::QueryBuilder::try_filter) is used with a type whose
+/// components are not registered in the world.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Error)]
+#[error("The components of {0} are not registered in the world")]
+pub struct QueryNotRegisteredError(pub &'static str);
@@ -1140,6 +1140,7 @@ unsafe impl<'__w, T: Component> WorldQuery for Ref<'__w, T> {
                         changed: changed.deref(),
                         this_run: fetch.this_run,
                         last_run: fetch.last_run,
+                        tracks_changes: T::CHANGE_DETECTION_ENABLED,
                     },
                 }
             }
@@ -1156,7 +1157,8 @@ unsafe impl<'__w, T: Component> WorldQuery for Ref<'__w, T> {
 
                 Ref {
                     value: component.deref(),
-                    ticks: Ticks::from_tick_cells(ticks, fetch.last_run, fetch.this_run),
+                    ticks: Ticks::from_tick_cells(ticks, fetch.last_run, fetch.this_run)
+                        .with_change_detection(T::CHANGE_DETECTION_ENABLED),
                 }
             }
         }
@@ -1323,6 +1325,7 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
                         changed: changed.deref_mut(),
                         this_run: fetch.this_run,
                         last_run: fetch.last_run,
+                        tracks_changes: T::CHANGE_DETECTION_ENABLED,
                     },
                 }
             }
@@ -1339,7 +1342,8 @@ unsafe impl<'__w, T: Component> WorldQuery for &'__w mut T {
 
                 Mut {
                     value: component.assert_unique().deref_mut(),
-                    ticks: TicksMut::from_tick_cells(ticks, fetch.last_run, fetch.this_run),
+                    ticks: TicksMut::from_tick_cells(ticks, fetch.last_run, fetch.this_run)
+                        .with_change_detection(T::CHANGE_DETECTION_ENABLED),
                 }
             }
         }
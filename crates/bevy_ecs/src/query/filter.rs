@@ -514,6 +514,20 @@ macro_rules! impl_tuple_query_filter {
 all_tuples!(impl_tuple_query_filter, 0, 15, F);
 all_tuples!(impl_or_query_filter, 0, 15, F, S);
 
+/// Rejects change detection filters on components that opted out of change detection.
+///
+/// [`ChangeDetectionEnabled::ASSERT`] is evaluated when a filter's state is initialized or looked up for a concrete `T`,
+/// so using [`Added`] or [`Changed`] with a component that has change detection disabled
+/// fails to compile instead of silently reporting meaningless results.
+struct ChangeDetectionEnabled<T>(PhantomData<T>);
+
+impl<T: Component> ChangeDetectionEnabled<T> {
+    const ASSERT: () = assert!(
+        T::CHANGE_DETECTION_ENABLED,
+        "Added and Changed filters cannot be used on a component with `#[component(change_detection = false)]`"
+    );
+}
+
 /// A filter on a component that only retains results added after the system last ran.
 ///
 /// A common use for this filter is one-time initialization.
@@ -527,6 +541,9 @@ all_tuples!(impl_or_query_filter, 0, 15, F, S);
 /// are visible only after deferred operations are applied,
 /// typically at the end of the schedule iteration.
 ///
+/// If change detection is turned off for the whole world with
+/// [`World::set_change_detection_enabled`], this filter treats every `T` as added.
+///
 /// # Time complexity
 ///
 /// `Added` is not [`ArchetypeFilter`], which practically means that
@@ -585,6 +602,7 @@ pub struct AddedFetch<'w> {
     sparse_set: Option<&'w ComponentSparseSet>,
     last_run: Tick,
     this_run: Tick,
+    detect_changes: bool,
 }
 
 /// SAFETY:
@@ -614,6 +632,7 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
                 .then(|| world.storages().sparse_sets.get(id).debug_checked_unwrap()),
             last_run,
             this_run,
+            detect_changes: world.is_change_detection_enabled(),
         }
     }
 
@@ -657,6 +676,9 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
         entity: Entity,
         table_row: TableRow,
     ) -> Self::Item<'w> {
+        if !fetch.detect_changes {
+            return true;
+        }
         match T::STORAGE_TYPE {
             StorageType::Table => {
                 // SAFETY: STORAGE_TYPE = Table
@@ -688,10 +710,12 @@ unsafe impl<T: Component> WorldQuery for Added<T> {
     }
 
     fn init_state(world: &mut World) -> ComponentId {
+        let () = ChangeDetectionEnabled::<T>::ASSERT;
         world.init_component::<T>()
     }
 
     fn get_state(world: &World) -> Option<ComponentId> {
+        let () = ChangeDetectionEnabled::<T>::ASSERT;
         world.component_id::<T>()
     }
 
@@ -733,6 +757,9 @@ impl<T: Component> QueryFilter for Added<T> {
 /// are visible only after deferred operations are applied,
 /// typically at the end of the schedule iteration.
 ///
+/// If change detection is turned off for the whole world with
+/// [`World::set_change_detection_enabled`], this filter treats every `T` as changed.
+///
 /// # Time complexity
 ///
 /// `Changed` is not [`ArchetypeFilter`], which practically means that
@@ -764,6 +791,21 @@ impl<T: Component> QueryFilter for Added<T> {
 /// }
 /// ```
 ///
+/// # Components without change detection
+///
+/// Components with `#[component(change_detection = false)]` don't record their mutations.
+/// Using them in a `Changed` or [`Added`] filter is rejected at compile time:
+///
+/// ```compile_fail
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// #[component(change_detection = false)]
+/// struct Velocity(f32);
+///
+/// let mut world = World::new();
+/// world.query_filtered::<Entity, Changed<Velocity>>();
+/// ```
+///
 /// # Examples
 ///
 /// ```
@@ -794,6 +836,7 @@ pub struct ChangedFetch<'w> {
     sparse_set: Option<&'w ComponentSparseSet>,
     last_run: Tick,
     this_run: Tick,
+    detect_changes: bool,
 }
 
 /// SAFETY:
@@ -823,6 +866,7 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
                 .then(|| world.storages().sparse_sets.get(id).debug_checked_unwrap()),
             last_run,
             this_run,
+            detect_changes: world.is_change_detection_enabled(),
         }
     }
 
@@ -866,6 +910,9 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
         entity: Entity,
         table_row: TableRow,
     ) -> Self::Item<'w> {
+        if !fetch.detect_changes {
+            return true;
+        }
         match T::STORAGE_TYPE {
             StorageType::Table => {
                 // SAFETY: STORAGE_TYPE = Table
@@ -897,10 +944,12 @@ unsafe impl<T: Component> WorldQuery for Changed<T> {
     }
 
     fn init_state(world: &mut World) -> ComponentId {
+        let () = ChangeDetectionEnabled::<T>::ASSERT;
         world.init_component::<T>()
     }
 
     fn get_state(world: &World) -> Option<ComponentId> {
+        let () = ChangeDetectionEnabled::<T>::ASSERT;
         world.component_id::<T>()
     }
 
@@ -144,6 +144,20 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     pub fn matched_archetypes(&self) -> impl Iterator<Item = ArchetypeId> + '_ {
         self.matched_archetypes.ones().map(ArchetypeId::new)
     }
+
+    /// Returns the number of heap bytes used to cache the tables and archetypes matched by this query.
+    ///
+    /// This grows with the number of archetypes in the world, which makes it useful to spot
+    /// long-lived queries whose caches keep growing. It doesn't include the fetch and filter state.
+    ///
+    /// The matched sets are counted by their capacity in bits, rounded up to whole bytes,
+    /// and the matched storage list by its capacity. The allocator may round these up further.
+    pub fn matched_storage_bytes(&self) -> usize {
+        let bitset_bytes = |bits: &FixedBitSet| bits.len().div_ceil(u8::BITS as usize);
+        bitset_bytes(&self.matched_tables)
+            + bitset_bytes(&self.matched_archetypes)
+            + self.matched_storage_ids.capacity() * std::mem::size_of::<StorageId>()
+    }
 }
 
 impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
@@ -214,11 +228,21 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
 
     /// Creates a new [`QueryState`] from a given [`QueryBuilder`] and inherits its [`FilteredAccess`].
     pub fn from_builder(builder: &mut QueryBuilder<D, F>) -> Self {
+        let mut state = Self::from_builder_uninitialized(builder);
+        state.update_archetypes(builder.world());
+        state
+    }
+
+    /// Creates a new [`QueryState`] from a given [`QueryBuilder`] without matching it against
+    /// the archetypes of the builder's [`World`] yet.
+    ///
+    /// See [`QueryBuilder::build_lazy`] for details.
+    pub(crate) fn from_builder_uninitialized(builder: &mut QueryBuilder<D, F>) -> Self {
         let mut fetch_state = D::init_state(builder.world_mut());
         let filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
 
-        let mut state = Self {
+        Self {
             world_id: builder.world().id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
@@ -233,9 +257,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
                 data = std::any::type_name::<D>(),
                 filter = std::any::type_name::<F>(),
             ),
-        };
-        state.update_archetypes(builder.world());
-        state
+        }
     }
 
     /// Checks if the query is empty for the given [`World`], where the last change and current tick are given.
@@ -1660,6 +1682,36 @@ mod tests {
     use crate::world::FilteredEntityRef;
     use crate::{component::Component, prelude::*, query::QueryEntityError};
 
+    #[test]
+    fn matched_storage_bytes() {
+        #[derive(Component)]
+        struct A;
+        #[derive(Component)]
+        struct B;
+        #[derive(Component)]
+        struct C;
+
+        let mut world = World::new();
+        let mut query = world.query::<&A>();
+        assert_eq!(0, query.matched_storage_bytes());
+
+        world.spawn(A);
+        world.spawn((A, B));
+        world.spawn((A, C));
+        query.update_archetypes(&world);
+        assert_eq!(3, query.matched_archetypes().count());
+        // Archetypes and tables 1 to 3 are matched, so each bitset holds 4 bits, in a single byte.
+        let ids_bytes = query.matched_storage_ids.capacity() * std::mem::size_of::<StorageId>();
+        assert!(ids_bytes >= 3 * std::mem::size_of::<StorageId>());
+        assert_eq!(2 + ids_bytes, query.matched_storage_bytes());
+
+        // Archetypes the query doesn't match, or that already exist, don't grow the cache.
+        world.spawn(A);
+        world.spawn(B);
+        query.update_archetypes(&world);
+        assert_eq!(2 + ids_bytes, query.matched_storage_bytes());
+    }
+
     #[test]
     fn get_many_unchecked_manual_uniqueness() {
         let mut world = World::new();
@@ -1,7 +1,7 @@
 use crate::prelude::Mut;
 use crate::reflect::AppTypeRegistry;
 use crate::system::{EntityCommands, Resource};
-use crate::world::Command;
+use crate::world::{Command, EntityWorldMut};
 use crate::{entity::Entity, reflect::ReflectComponent, world::World};
 use bevy_reflect::{Reflect, TypeRegistry};
 use std::borrow::Cow;
@@ -183,6 +183,76 @@ impl ReflectCommandExt for EntityCommands<'_> {
     }
 }
 
+impl<'w> EntityWorldMut<'w> {
+    /// Adds the given boxed reflect component to the entity using the reflection data in
+    /// [`AppTypeRegistry`].
+    ///
+    /// This is the [`World`] counterpart of [`ReflectCommandExt::insert_reflect`], and panics
+    /// in the same cases.
+    pub fn insert_reflect(&mut self, component: Box<dyn Reflect>) -> &mut Self {
+        let entity = self.id();
+        self.world_scope(|world| InsertReflect { entity, component }.apply(world));
+        self
+    }
+
+    /// Same as [`insert_reflect`](EntityWorldMut::insert_reflect), but using the `T` resource
+    /// as type registry instead of [`AppTypeRegistry`].
+    pub fn insert_reflect_with_registry<T: Resource + AsRef<TypeRegistry>>(
+        &mut self,
+        component: Box<dyn Reflect>,
+    ) -> &mut Self {
+        let entity = self.id();
+        self.world_scope(|world| {
+            InsertReflectWithRegistry::<T> {
+                entity,
+                _t: PhantomData,
+                component,
+            }
+            .apply(world);
+        });
+        self
+    }
+
+    /// Removes from the entity the component with the given type path using the reflection data
+    /// in [`AppTypeRegistry`].
+    ///
+    /// This is the [`World`] counterpart of [`ReflectCommandExt::remove_reflect`].
+    pub fn remove_reflect(
+        &mut self,
+        component_type_path: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        let entity = self.id();
+        let component_type_path = component_type_path.into();
+        self.world_scope(|world| {
+            RemoveReflect {
+                entity,
+                component_type_path,
+            }
+            .apply(world);
+        });
+        self
+    }
+
+    /// Same as [`remove_reflect`](EntityWorldMut::remove_reflect), but using the `T` resource
+    /// as type registry instead of [`AppTypeRegistry`].
+    pub fn remove_reflect_with_registry<T: Resource + AsRef<TypeRegistry>>(
+        &mut self,
+        component_type_name: impl Into<Cow<'static, str>>,
+    ) -> &mut Self {
+        let entity = self.id();
+        let component_type_name = component_type_name.into();
+        self.world_scope(|world| {
+            RemoveReflectWithRegistry::<T> {
+                entity,
+                _t: PhantomData,
+                component_type_name,
+            }
+            .apply(world);
+        });
+        self
+    }
+}
+
 /// Helper function to add a reflect component to a given entity
 fn insert_reflect(
     world: &mut World,
@@ -458,4 +528,27 @@ mod tests {
 
         assert_eq!(world.entity(entity).get::<ComponentA>(), None);
     }
+
+    #[test]
+    fn insert_and_remove_reflected_world() {
+        let mut world = World::new();
+
+        let type_registry = AppTypeRegistry::default();
+        {
+            let mut registry = type_registry.write();
+            registry.register::<ComponentA>();
+            registry.register_type_data::<ComponentA, ReflectComponent>();
+        }
+        world.insert_resource(type_registry);
+
+        let boxed_reflect_component_a = Box::new(ComponentA(916)) as Box<dyn Reflect>;
+        let type_path = boxed_reflect_component_a.reflect_type_path().to_owned();
+
+        let mut entity = world.spawn_empty();
+        entity.insert_reflect(boxed_reflect_component_a);
+        assert_eq!(entity.get::<ComponentA>(), Some(&ComponentA(916)));
+
+        entity.remove_reflect(type_path);
+        assert_eq!(entity.get::<ComponentA>(), None);
+    }
 }
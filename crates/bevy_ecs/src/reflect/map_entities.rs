@@ -1,6 +1,8 @@
 use crate::{
     component::Component,
     entity::{Entity, EntityHashMap, MapEntities, SceneEntityMapper},
+    event::{Event, Events},
+    system::Resource,
     world::World,
 };
 use bevy_reflect::FromType;
@@ -73,3 +75,99 @@ impl<C: Component + MapEntities> FromType<C> for ReflectMapEntities {
         }
     }
 }
+
+/// For a specific type of resource, this maps any fields with values of type [`Entity`] to a new world.
+/// Since a given `Entity` ID is only valid for the world it came from, when performing deserialization
+/// any stored IDs need to be re-allocated in the destination world.
+///
+/// See [`SceneEntityMapper`] and [`MapEntities`] for more information.
+#[derive(Clone)]
+pub struct ReflectMapEntitiesResource {
+    map_entities: fn(&mut World, &mut SceneEntityMapper),
+}
+
+impl ReflectMapEntitiesResource {
+    /// A method for applying [`MapEntities`] behavior to the resource, using the mappings in an [`EntityHashMap<Entity>`].
+    pub fn map_entities(&self, world: &mut World, entity_map: &mut EntityHashMap<Entity>) {
+        SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
+            (self.map_entities)(world, mapper);
+        });
+    }
+}
+
+impl<R: Resource + MapEntities> FromType<R> for ReflectMapEntitiesResource {
+    fn from_type() -> Self {
+        ReflectMapEntitiesResource {
+            map_entities: |world, entity_mapper| {
+                if let Some(mut resource) = world.get_resource_mut::<R>() {
+                    resource.map_entities(entity_mapper);
+                }
+            },
+        }
+    }
+}
+
+/// For a specific type of event, this maps any fields with values of type [`Entity`] to a new world,
+/// in every event still stored in its [`Events`] resource.
+///
+/// See [`SceneEntityMapper`] and [`MapEntities`] for more information.
+#[derive(Clone)]
+pub struct ReflectMapEntitiesEvent {
+    map_entities: fn(&mut World, &mut SceneEntityMapper),
+}
+
+impl ReflectMapEntitiesEvent {
+    /// A method for applying [`MapEntities`] behavior to the stored events, using the mappings in an [`EntityHashMap<Entity>`].
+    pub fn map_entities(&self, world: &mut World, entity_map: &mut EntityHashMap<Entity>) {
+        SceneEntityMapper::world_scope(entity_map, world, |world, mapper| {
+            (self.map_entities)(world, mapper);
+        });
+    }
+}
+
+impl<E: Event + MapEntities> FromType<E> for ReflectMapEntitiesEvent {
+    fn from_type() -> Self {
+        ReflectMapEntitiesEvent {
+            map_entities: |world, entity_mapper| {
+                if let Some(mut events) = world.get_resource_mut::<Events<E>>() {
+                    for event in events.iter_mut() {
+                        event.map_entities(entity_mapper);
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_reflect::{FromType, Reflect};
+
+    use super::ReflectMapEntitiesEvent;
+    use crate::{
+        self as bevy_ecs,
+        entity::{Entity, EntityHashMap, MapEntities},
+        event::{Event, Events},
+        world::World,
+    };
+
+    #[derive(Event, MapEntities, Reflect)]
+    struct Targeted(Entity);
+
+    #[test]
+    fn map_entities_in_events() {
+        let mut world = World::new();
+        let [from, to] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.init_resource::<Events<Targeted>>();
+        world.send_event(Targeted(from));
+
+        let mut entity_map = EntityHashMap::default();
+        entity_map.insert(from, to);
+        <ReflectMapEntitiesEvent as FromType<Targeted>>::from_type()
+            .map_entities(&mut world, &mut entity_map);
+
+        let events = world.resource::<Events<Targeted>>();
+        let targets: Vec<_> = events.iter_current_update_events().map(|e| e.0).collect();
+        assert_eq!(targets, [to]);
+    }
+}
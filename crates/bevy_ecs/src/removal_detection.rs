@@ -12,11 +12,13 @@ use crate::{
 };
 
 use std::{
+    any::Any,
     fmt::Debug,
     iter,
     marker::PhantomData,
     ops::{Deref, DerefMut},
     option,
+    sync::Arc,
 };
 
 /// Wrapper around [`Entity`] for [`RemovedComponents`].
@@ -30,6 +32,40 @@ impl From<RemovedComponentEntity> for Entity {
     }
 }
 
+/// The [`Tick`] and optional removed value of a [`RemovedComponentEntity`].
+///
+/// Sent alongside each [`RemovedComponentEntity`] so that both buffers share their [`EventId`]s.
+#[derive(Event, Debug, Clone, Default)]
+struct RemovedComponentData {
+    tick: Option<Tick>,
+    value: Option<Arc<dyn Any + Send + Sync>>,
+}
+
+/// An [`Event`] sent whenever an entity is despawned, listing the components it had.
+///
+/// Unlike [`RemovedComponents`], which is tracked per component type, this reports the whole
+/// entity at once, together with the [`Tick`] of the despawn. Systems that run late or at a lower
+/// frequency, such as replication or cleanup, can rely on it without racing the despawn.
+///
+/// These events are only recorded when an [`Events<EntityDespawned>`] resource exists in the world,
+/// for example after calling `App::add_event::<EntityDespawned>()`.
+///
+/// They are sent by every despawn that goes through [`World::despawn`] or [`EntityWorldMut::despawn`],
+/// which includes [`EntityCommands::despawn`](crate::system::EntityCommands::despawn), and for every
+/// entity removed by [`World::clear_entities`]. Entities are not reported when the [`World`]
+/// itself is dropped, nor by [`World::clear_all`], which clears the events along with the other resources.
+///
+/// [`EntityWorldMut::despawn`]: crate::world::EntityWorldMut::despawn
+#[derive(Event, Debug, Clone, PartialEq, Eq)]
+pub struct EntityDespawned {
+    /// The despawned entity.
+    pub entity: Entity,
+    /// The change tick at which the entity was despawned.
+    pub tick: Tick,
+    /// The components the entity had when it was despawned.
+    pub components: Vec<ComponentId>,
+}
+
 /// Wrapper around a [`ManualEventReader<RemovedComponentEntity>`] so that we
 /// can differentiate events between components.
 #[derive(Debug)]
@@ -67,6 +103,7 @@ impl<T: Component> DerefMut for RemovedComponentReader<T> {
 #[derive(Default, Debug)]
 pub struct RemovedComponentEvents {
     event_sets: SparseSet<ComponentId, Events<RemovedComponentEntity>>,
+    data_sets: SparseSet<ComponentId, Events<RemovedComponentData>>,
 }
 
 impl RemovedComponentEvents {
@@ -81,6 +118,9 @@ impl RemovedComponentEvents {
         for (_component_id, events) in self.event_sets.iter_mut() {
             events.update();
         }
+        for (_component_id, data) in self.data_sets.iter_mut() {
+            data.update();
+        }
     }
 
     /// Returns an iterator over components and their entity events.
@@ -98,9 +138,49 @@ impl RemovedComponentEvents {
 
     /// Sends a removal event for the specified component.
     pub fn send(&mut self, component_id: impl Into<ComponentId>, entity: Entity) {
+        self.send_with_data(component_id.into(), entity, RemovedComponentData::default());
+    }
+
+    /// Sends a removal event for the specified component, removed at the given `tick`.
+    pub fn send_with_tick(
+        &mut self,
+        component_id: impl Into<ComponentId>,
+        entity: Entity,
+        tick: Tick,
+    ) {
+        self.send_with_value(component_id, entity, tick, None);
+    }
+
+    /// Sends a removal event for the specified component, removed at the given `tick`
+    /// and carrying a copy of the removed value.
+    ///
+    /// See [`Component::REMOVED_VALUE`].
+    pub fn send_with_value(
+        &mut self,
+        component_id: impl Into<ComponentId>,
+        entity: Entity,
+        tick: Tick,
+        value: Option<Arc<dyn Any + Send + Sync>>,
+    ) {
+        let data = RemovedComponentData {
+            tick: Some(tick),
+            value,
+        };
+        self.send_with_data(component_id.into(), entity, data);
+    }
+
+    fn send_with_data(
+        &mut self,
+        component_id: ComponentId,
+        entity: Entity,
+        data: RemovedComponentData,
+    ) {
         self.event_sets
-            .get_or_insert_with(component_id.into(), Default::default)
+            .get_or_insert_with(component_id, Default::default)
             .send(RemovedComponentEntity(entity));
+        self.data_sets
+            .get_or_insert_with(component_id, Default::default)
+            .send(data);
     }
 }
 
@@ -109,8 +189,12 @@ impl RemovedComponentEvents {
 ///
 /// This acts effectively the same as an [`EventReader`](crate::event::EventReader).
 ///
-/// Note that this does not allow you to see which data existed before removal.
-/// If you need this, you will need to track the component data value on your own,
+/// The [`Tick`] at which each component was removed is available through [`RemovedComponents::read_with_ticks`].
+///
+/// By default, this does not allow you to see which data existed before removal.
+/// Components deriving `#[component(removed_value)]` keep a clone of each removed value,
+/// which can be read with [`RemovedComponents::read_with_values`].
+/// Otherwise, you will need to track the component data value on your own,
 /// using a regularly scheduled system that requests `Query<(Entity, &T), Changed<T>>`
 /// and stores the data somewhere safe to later cross-reference.
 ///
@@ -223,6 +307,43 @@ impl<'w, 's, T: Component> RemovedComponents<'w, 's, T> {
             .map(map_id_events)
     }
 
+    /// Like [`read`](Self::read), except also returning the [`Tick`] at which each component was removed.
+    ///
+    /// The tick is `None` for events sent through [`RemovedComponentEvents::send`].
+    pub fn read_with_ticks(&mut self) -> impl Iterator<Item = (Entity, Option<Tick>)> + '_ {
+        self.read_with_data()
+            .map(|(entity, data)| (entity, data.and_then(|data| data.tick)))
+    }
+
+    /// Like [`read_with_ticks`](Self::read_with_ticks), except also returning the removed value.
+    ///
+    /// The value is only captured for components opting into it with [`Component::REMOVED_VALUE`],
+    /// and is `None` otherwise.
+    pub fn read_with_values(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, Option<Tick>, Option<&T>)> + '_ {
+        self.read_with_data().map(|(entity, data)| {
+            let tick = data.and_then(|data| data.tick);
+            let value = data
+                .and_then(|data| data.value.as_deref())
+                .and_then(|value| value.downcast_ref());
+            (entity, tick, value)
+        })
+    }
+
+    fn read_with_data(
+        &mut self,
+    ) -> impl Iterator<Item = (Entity, Option<&RemovedComponentData>)> + '_ {
+        let event_sets = self.event_sets;
+        let data = event_sets.data_sets.get(self.component_id.get());
+        self.read_with_id().map(move |(entity, id)| {
+            let data = data
+                .and_then(|data| data.get_event(id.id))
+                .map(|(data, _)| data);
+            (entity, data)
+        })
+    }
+
     /// Determines the number of removal events available to be read from this [`RemovedComponents`] without consuming any.
     pub fn len(&self) -> usize {
         self.events()
@@ -17,6 +17,7 @@ pub struct ResourceData<const SEND: bool> {
     type_name: String,
     id: ArchetypeComponentId,
     origin_thread_id: Option<ThreadId>,
+    change_detection_enabled: bool,
 }
 
 impl<const SEND: bool> Drop for ResourceData<SEND> {
@@ -123,6 +124,14 @@ impl<const SEND: bool> ResourceData<SEND> {
         })
     }
 
+    /// Returns `true` if mutations of this resource record change ticks.
+    ///
+    /// See [`Resource::CHANGE_DETECTION_ENABLED`](crate::system::Resource::CHANGE_DETECTION_ENABLED).
+    #[inline]
+    pub(crate) fn change_detection_enabled(&self) -> bool {
+        self.change_detection_enabled
+    }
+
     /// Returns a mutable reference to the resource, if it exists.
     ///
     /// # Panics
@@ -134,7 +143,8 @@ impl<const SEND: bool> ResourceData<SEND> {
             // SAFETY: We have exclusive access to the underlying storage.
             value: unsafe { ptr.assert_unique() },
             // SAFETY: We have exclusive access to the underlying storage.
-            ticks: unsafe { TicksMut::from_tick_cells(ticks, last_run, this_run) },
+            ticks: unsafe { TicksMut::from_tick_cells(ticks, last_run, this_run) }
+                .with_change_detection(self.change_detection_enabled),
         })
     }
 
@@ -333,6 +343,7 @@ impl<const SEND: bool> Resources<SEND> {
                 type_name: String::from(component_info.name()),
                 id: f(),
                 origin_thread_id: None,
+                change_detection_enabled: component_info.change_detection_enabled(),
             }
         })
     }
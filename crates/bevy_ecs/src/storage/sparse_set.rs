@@ -327,6 +327,11 @@ impl ComponentSparseSet {
     pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
         self.dense.check_change_ticks(change_tick);
     }
+
+    /// Overwrites the added and changed ticks of every value in the sparse set with `tick`.
+    pub(crate) fn set_all_ticks(&mut self, tick: Tick) {
+        self.dense.set_all_ticks(tick);
+    }
 }
 
 /// A data structure that blends dense and sparse storage
@@ -501,6 +501,17 @@ impl Column {
             component_ticks.get_mut().check_tick(change_tick);
         }
     }
+
+    /// Overwrites the added and changed ticks of every value in the column with `tick`.
+    #[inline]
+    pub(crate) fn set_all_ticks(&mut self, tick: Tick) {
+        for added in &mut self.added_ticks {
+            *added.get_mut() = tick;
+        }
+        for changed in &mut self.changed_ticks {
+            *changed.get_mut() = tick;
+        }
+    }
 }
 
 /// A builder type for constructing [`Table`]s.
@@ -895,6 +906,15 @@ impl Tables {
             table.check_change_ticks(change_tick);
         }
     }
+
+    /// Overwrites the added and changed ticks of every value of `component_id` with `tick`.
+    pub(crate) fn set_all_ticks(&mut self, component_id: ComponentId, tick: Tick) {
+        for table in &mut self.tables {
+            if let Some(column) = table.get_column_mut(component_id) {
+                column.set_all_ticks(tick);
+            }
+        }
+    }
 }
 
 impl Index<TableId> for Tables {
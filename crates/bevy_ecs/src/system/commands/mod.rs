@@ -849,6 +849,17 @@ impl EntityCommands<'_> {
         self.add(try_insert(bundle))
     }
 
+    /// Adds a [`Bundle`] of components to the entity without triggering change detection.
+    ///
+    /// See [`EntityWorldMut::insert_unchanged`] for more details.
+    ///
+    /// # Panics
+    ///
+    /// The command will panic when applied if the associated entity does not exist.
+    pub fn insert_unchanged(&mut self, bundle: impl Bundle) -> &mut Self {
+        self.add(insert_unchanged(bundle))
+    }
+
     /// Removes a [`Bundle`] of components from the entity.
     ///
     /// # Example
@@ -896,6 +907,13 @@ impl EntityCommands<'_> {
         self.add(remove_by_id(component_id))
     }
 
+    /// Marks the component with the given [`ComponentId`] as changed, without accessing its value.
+    ///
+    /// See [`EntityWorldMut::mark_changed_by_id`] for more details.
+    pub fn mark_changed_by_id(&mut self, component_id: ComponentId) -> &mut Self {
+        self.add(mark_changed_by_id(component_id))
+    }
+
     /// Despawns the entity.
     ///
     /// See [`World::despawn`] for more details.
@@ -1101,6 +1119,17 @@ fn try_insert(bundle: impl Bundle) -> impl EntityCommand {
     }
 }
 
+/// An [`EntityCommand`] that adds the components in a [`Bundle`] to an entity without triggering change detection.
+fn insert_unchanged<T: Bundle>(bundle: T) -> impl EntityCommand {
+    move |entity: Entity, world: &mut World| {
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            entity.insert_unchanged(bundle);
+        } else {
+            panic!("error[B0003]: Could not insert a bundle (of type `{}`) for entity {:?} because it doesn't exist in this World. See: https://bevyengine.org/learn/errors/#b0003", std::any::type_name::<T>(), entity);
+        }
+    }
+}
+
 /// An [`EntityCommand`] that removes components from an entity.
 /// For a [`Bundle`] type `T`, this will remove any components in the bundle.
 /// Any components in the bundle that aren't found on the entity will be ignored.
@@ -1122,6 +1151,15 @@ fn remove_by_id(component_id: ComponentId) -> impl EntityCommand {
     }
 }
 
+/// An [`EntityCommand`] that marks the component with a provided [`ComponentId`] as changed.
+fn mark_changed_by_id(component_id: ComponentId) -> impl EntityCommand {
+    move |entity: Entity, world: &mut World| {
+        if let Some(mut entity) = world.get_entity_mut(entity) {
+            entity.mark_changed_by_id(component_id);
+        }
+    }
+}
+
 /// An [`EntityCommand`] that removes components from an entity.
 /// For a [`Bundle`] type `T`, this will remove all components except those in the bundle.
 /// Any components in the bundle that aren't found on the entity will be ignored.
@@ -963,6 +963,68 @@ mod tests {
         assert_eq!(world.resource::<NSystems>().0, 2);
     }
 
+    #[test]
+    fn removal_tracking_ticks_and_values() {
+        #[derive(Component, Clone, Debug, PartialEq)]
+        #[component(removed_value)]
+        struct Tracked(u32);
+
+        #[derive(Resource)]
+        struct Removals {
+            tick: Tick,
+            entities: [Entity; 3],
+        }
+
+        let mut world = World::new();
+        let removed_entity = world.spawn((Tracked(1), W(1))).id();
+        let despawned_entity = world.spawn((Tracked(2), W(2))).id();
+        let taken_entity = world.spawn(Tracked(3)).id();
+        world.clear_trackers();
+
+        let tick = world.change_tick();
+        world.entity_mut(removed_entity).remove::<Tracked>();
+        world.entity_mut(despawned_entity).despawn();
+        assert_eq!(
+            world.entity_mut(taken_entity).take::<Tracked>(),
+            Some(Tracked(3))
+        );
+        world.insert_resource(Removals {
+            tick,
+            entities: [removed_entity, despawned_entity, taken_entity],
+        });
+
+        fn validate(
+            mut removed_tracked: RemovedComponents<Tracked>,
+            mut removed_i32: RemovedComponents<W<i32>>,
+            mut removed_i32_values: RemovedComponents<W<i32>>,
+            removals: Res<Removals>,
+        ) {
+            let [removed_entity, despawned_entity, taken_entity] = removals.entities;
+            let tick = Some(removals.tick);
+            assert_eq!(
+                removed_tracked.read_with_values().collect::<Vec<_>>(),
+                &[
+                    (removed_entity, tick, Some(&Tracked(1))),
+                    (despawned_entity, tick, Some(&Tracked(2))),
+                    (taken_entity, tick, Some(&Tracked(3))),
+                ],
+                "removal events carry the removal tick and a copy of opted-in component values"
+            );
+            assert_eq!(
+                removed_i32.read_with_ticks().collect::<Vec<_>>(),
+                &[(despawned_entity, tick)],
+                "removal events carry the removal tick for every component"
+            );
+            assert_eq!(
+                removed_i32_values.read_with_values().collect::<Vec<_>>(),
+                &[(despawned_entity, tick, None)],
+                "values are only captured for components opting into it"
+            );
+        }
+
+        run_system(&mut world, validate);
+    }
+
     #[test]
     fn world_collections_system() {
         let mut world = World::default();
@@ -1,4 +1,5 @@
 use crate::{
+    archetype::ArchetypeId,
     batching::BatchingStrategy,
     component::Tick,
     entity::Entity,
@@ -1254,6 +1255,36 @@ impl<'w, 's, D: QueryData, F: QueryFilter> Query<'w, 's, D, F> {
         }
     }
 
+    /// Returns the archetypes matched by this query.
+    ///
+    /// See [`QueryState::matched_archetypes`] for details.
+    #[inline]
+    pub fn matched_archetypes(&self) -> impl Iterator<Item = ArchetypeId> + '_ {
+        self.state.matched_archetypes()
+    }
+
+    /// Returns the number of heap bytes this query uses to cache the tables and archetypes it matches.
+    ///
+    /// See [`QueryState::matched_storage_bytes`] for details.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Component)]
+    /// # struct Enemy;
+    /// #
+    /// fn report_query_cache(enemies: Query<&Enemy>) {
+    ///     println!("enemy query cache: {} bytes", enemies.matched_storage_bytes());
+    /// }
+    /// # bevy_ecs::system::assert_is_system(report_query_cache);
+    /// ```
+    #[inline]
+    pub fn matched_storage_bytes(&self) -> usize {
+        self.state.matched_storage_bytes()
+    }
+
     /// Returns `true` if the given [`Entity`] matches the query.
     ///
     /// This is always guaranteed to run in `O(1)` time.
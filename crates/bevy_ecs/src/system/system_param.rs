@@ -2,7 +2,7 @@ pub use crate::change_detection::{NonSendMut, Res, ResMut};
 use crate::{
     archetype::{Archetype, Archetypes},
     bundle::Bundles,
-    change_detection::{Ticks, TicksMut},
+    change_detection::TicksMut,
     component::{ComponentId, ComponentTicks, Components, Tick},
     entity::Entities,
     query::{
@@ -431,8 +431,27 @@ impl_param_set!();
 /// }
 /// ```
 ///
+/// # Disabling change detection
+///
+/// Resources that are mutated constantly, or whose changes are never observed, can opt out of
+/// change detection with `#[resource(change_detection = false)]`.
+/// Mutating such a resource does not write its change tick, and it is always reported as changed,
+/// whether it is accessed through [`Res`], [`ResMut`] or directly on the [`World`](crate::world::World).
+///
+/// ```
+/// # use bevy_ecs::system::Resource;
+/// #[derive(Resource)]
+/// #[resource(change_detection = false)]
+/// struct FrameScratch(Vec<u8>);
+/// ```
+///
 /// [`Exclusive`]: https://doc.rust-lang.org/nightly/std/sync/struct.Exclusive.html
-pub trait Resource: Send + Sync + 'static {}
+pub trait Resource: Send + Sync + 'static {
+    /// A constant indicating whether mutations of this resource record change ticks.
+    ///
+    /// This is configured via the derive attribute `#[resource(change_detection = false)]`.
+    const CHANGE_DETECTION_ENABLED: bool = true;
+}
 
 // SAFETY: Res only reads a single World resource
 unsafe impl<'a, T: Resource> ReadOnlySystemParam for Res<'a, T> {}
@@ -476,7 +495,7 @@ unsafe impl<'a, T: Resource> SystemParam for Res<'a, T> {
         change_tick: Tick,
     ) -> Self::Item<'w, 's> {
         let (ptr, ticks) = world
-            .get_resource_with_ticks(component_id)
+            .get_resource_with_ticks(component_id, system_meta.last_run, change_tick)
             .unwrap_or_else(|| {
                 panic!(
                     "Resource requested by {} does not exist: {}",
@@ -486,12 +505,7 @@ unsafe impl<'a, T: Resource> SystemParam for Res<'a, T> {
             });
         Res {
             value: ptr.deref(),
-            ticks: Ticks {
-                added: ticks.added.deref(),
-                changed: ticks.changed.deref(),
-                last_run: system_meta.last_run,
-                this_run: change_tick,
-            },
+            ticks,
         }
     }
 }
@@ -516,15 +530,10 @@ unsafe impl<'a, T: Resource> SystemParam for Option<Res<'a, T>> {
         change_tick: Tick,
     ) -> Self::Item<'w, 's> {
         world
-            .get_resource_with_ticks(component_id)
+            .get_resource_with_ticks(component_id, system_meta.last_run, change_tick)
             .map(|(ptr, ticks)| Res {
                 value: ptr.deref(),
-                ticks: Ticks {
-                    added: ticks.added.deref(),
-                    changed: ticks.changed.deref(),
-                    last_run: system_meta.last_run,
-                    this_run: change_tick,
-                },
+                ticks,
             })
     }
 }
@@ -586,6 +595,7 @@ unsafe impl<'a, T: Resource> SystemParam for ResMut<'a, T> {
                 changed: value.ticks.changed,
                 last_run: system_meta.last_run,
                 this_run: change_tick,
+                tracks_changes: value.ticks.tracks_changes,
             },
         }
     }
@@ -616,6 +626,7 @@ unsafe impl<'a, T: Resource> SystemParam for Option<ResMut<'a, T>> {
                     changed: value.ticks.changed,
                     last_run: system_meta.last_run,
                     this_run: change_tick,
+                    tracks_changes: value.ticks.tracks_changes,
                 },
             })
     }
@@ -1,11 +1,13 @@
 use crate::{
     archetype::{Archetype, ArchetypeId, Archetypes},
     bundle::{Bundle, BundleId, BundleInfo, BundleInserter, DynamicBundle},
-    change_detection::MutUntyped,
-    component::{Component, ComponentId, ComponentTicks, Components, StorageType},
+    change_detection::{DetectChangesMut, MutUntyped},
+    component::{
+        Component, ComponentId, ComponentInfo, ComponentTicks, Components, StorageType, Tick,
+    },
     entity::{Entities, Entity, EntityLocation},
     query::{Access, DebugCheckedUnwrap},
-    removal_detection::RemovedComponentEvents,
+    removal_detection::{EntityDespawned, RemovedComponentEvents},
     storage::Storages,
     world::{Mut, World},
 };
@@ -140,6 +142,24 @@ impl<'w> EntityRef<'w> {
         unsafe { self.0.get_change_ticks_by_id(component_id) }
     }
 
+    /// Returns the [`ComponentId`]s of the components of this entity that were added or changed
+    /// after `since`.
+    ///
+    /// This can be used by replication or save systems that keep their own baseline tick,
+    /// to find what needs to be sent or written for an entity without knowing its component types.
+    pub fn changed_components_since(&self, since: Tick) -> impl Iterator<Item = ComponentId> + 'w {
+        let this_run = self.0.world().change_tick();
+        let entity = self.0;
+        self.0
+            .archetype()
+            .components()
+            .filter(move |&component_id| {
+                // SAFETY: We have read-only access to all components of this entity.
+                unsafe { entity.get_change_ticks_by_id(component_id) }
+                    .is_some_and(|ticks| ticks.is_changed(since, this_run))
+            })
+    }
+
     /// Gets the component of the given [`ComponentId`] from the entity.
     ///
     /// **You should prefer to use the typed API where possible and only
@@ -749,6 +769,22 @@ impl<'w> EntityWorldMut<'w> {
         unsafe { self.as_unsafe_entity_cell().get_mut_by_id(component_id) }
     }
 
+    /// Marks the component of the given [`ComponentId`] as changed, without accessing its value.
+    ///
+    /// This is useful when a component was mutated through a type-erased pointer or reflection,
+    /// and change detection should still pick up the mutation.
+    /// Does nothing if the entity does not have the component.
+    ///
+    /// **You should prefer to use the typed API [`EntityWorldMut::get_mut`] where possible and only
+    /// use this in cases where the actual component types are not known at
+    /// compile time.**
+    pub fn mark_changed_by_id(&mut self, component_id: ComponentId) -> &mut Self {
+        if let Some(mut component) = self.get_mut_by_id(component_id) {
+            component.set_changed();
+        }
+        self
+    }
+
     /// Consumes `self` and gets a [`MutUntyped<'w>`] of the component with the world `'w` lifetime
     /// of the given [`ComponentId`] from the entity.
     ///
@@ -774,6 +810,40 @@ impl<'w> EntityWorldMut<'w> {
         self
     }
 
+    /// Adds a [`Bundle`] of components to the entity without triggering change detection.
+    ///
+    /// Components the entity already had keep their previous change ticks, and newly added
+    /// components are reported as neither added nor changed.
+    /// This is intended for rollback and resimulation, where restoring a prior state must not
+    /// retrigger every [`Changed`](crate::query::Changed) system downstream.
+    ///
+    /// This will overwrite any previous value(s) of the same component type.
+    pub fn insert_unchanged<T: Bundle>(&mut self, bundle: T) -> &mut Self {
+        let world = &mut *self.world;
+        let bundle_id = world
+            .bundles
+            .init_info::<T>(&mut world.components, &mut world.storages);
+        // SAFETY: We just ensured this bundle exists
+        let bundle_info = unsafe { self.world.bundles.get_unchecked(bundle_id) };
+        let previous_ticks = bundle_info
+            .components()
+            .iter()
+            .map(|&component_id| (component_id, self.get_change_ticks_by_id(component_id)))
+            .collect::<Vec<_>>();
+
+        self.insert(bundle);
+
+        let expired = Tick::expired(self.world.change_tick());
+        for (component_id, ticks) in previous_ticks {
+            let Some(mut component) = self.get_mut_by_id(component_id) else {
+                continue;
+            };
+            *component.ticks.added = ticks.map_or(expired, |ticks| ticks.added);
+            *component.ticks.changed = ticks.map_or(expired, |ticks| ticks.changed);
+        }
+        self
+    }
+
     /// Inserts a dynamic [`Component`] into the entity.
     ///
     /// This will overwrite any previous value(s) of the same component type.
@@ -813,6 +883,38 @@ impl<'w> EntityWorldMut<'w> {
         self
     }
 
+    /// Inserts the default value of a dynamic [`Component`] into the entity, using the constructor
+    /// registered with `#[component(register_default)]`.
+    ///
+    /// This will overwrite any previous value of the same component type.
+    ///
+    /// You should prefer to use the typed API [`EntityWorldMut::insert`] where possible.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the component does not exist in this world or has no registered default constructor.
+    /// See [`ComponentInfo::default_constructor`].
+    pub fn insert_default_by_id(&mut self, component_id: ComponentId) -> &mut Self {
+        let constructor = self
+            .world
+            .components()
+            .get_info(component_id)
+            .and_then(ComponentInfo::default_constructor)
+            .unwrap_or_else(|| {
+                panic!("Component {component_id:?} has no registered default constructor")
+            });
+        constructor(&mut |ptr| {
+            // SAFETY:
+            // - `component_id` was retrieved from this world's components
+            // - default constructors are only registered by `ComponentDescriptor::new::<T>`,
+            //   and only produce values of `T`, the type represented by `component_id`
+            unsafe {
+                self.insert_by_id(component_id, ptr);
+            }
+        });
+        self
+    }
+
     /// Inserts a dynamic [`Bundle`] into the entity.
     ///
     /// This will overwrite any previous value(s) of the same component type.
@@ -905,6 +1007,7 @@ impl<'w> EntityWorldMut<'w> {
             }
         }
 
+        let change_tick = world.change_tick();
         let archetypes = &mut world.archetypes;
         let storages = &mut world.storages;
         let components = &mut world.components;
@@ -912,6 +1015,23 @@ impl<'w> EntityWorldMut<'w> {
         let removed_components = &mut world.removed_components;
 
         let entity = self.entity;
+        // Removal events are sent before moving anything out of storage, so that a panicking
+        // `Component::REMOVED_VALUE` leaves the entity untouched.
+        for component_id in bundle_info.iter_components() {
+            // SAFETY: the entity has every component of the bundle, none of which has been moved yet
+            unsafe {
+                send_removed_component(
+                    storages,
+                    components,
+                    removed_components,
+                    component_id,
+                    entity,
+                    old_location,
+                    change_tick,
+                );
+            }
+        }
+
         let mut bundle_components = bundle_info.iter_components();
         // SAFETY: bundle components are iterated in order, which guarantees that the component type
         // matches
@@ -922,14 +1042,7 @@ impl<'w> EntityWorldMut<'w> {
                 // - entity location is valid
                 // - table row is removed below, without dropping the contents
                 // - `components` comes from the same world as `storages`
-                take_component(
-                    storages,
-                    components,
-                    removed_components,
-                    component_id,
-                    entity,
-                    old_location,
-                )
+                take_component(storages, components, component_id, entity, old_location)
             })
         };
 
@@ -1082,11 +1195,28 @@ impl<'w> EntityWorldMut<'w> {
             }
         }
 
+        let change_tick = world.change_tick();
         let old_archetype = &world.archetypes[location.archetype_id];
+        // Removal events are sent before dropping anything, so that a panicking
+        // `Component::REMOVED_VALUE` leaves the entity untouched.
+        for component_id in bundle_info.iter_components() {
+            if old_archetype.contains(component_id) {
+                // SAFETY: the component is part of the old archetype and has not been dropped yet
+                unsafe {
+                    send_removed_component(
+                        &world.storages,
+                        &world.components,
+                        &mut world.removed_components,
+                        component_id,
+                        entity,
+                        location,
+                        change_tick,
+                    );
+                }
+            }
+        }
         for component_id in bundle_info.iter_components() {
             if old_archetype.contains(component_id) {
-                world.removed_components.send(component_id, entity);
-
                 // Make sure to drop components stored in sparse sets.
                 // Dense components are dropped later in `move_to_and_drop_missing_unchecked`.
                 if let Some(StorageType::SparseSet) = old_archetype.get_storage_type(component_id) {
@@ -1200,8 +1330,27 @@ impl<'w> EntityWorldMut<'w> {
             }
         }
 
+        let change_tick = world.change_tick();
         for component_id in archetype.components() {
-            world.removed_components.send(component_id, self.entity);
+            // SAFETY: the component is part of the entity's archetype and has not been dropped yet
+            unsafe {
+                send_removed_component(
+                    &world.storages,
+                    &world.components,
+                    &mut world.removed_components,
+                    component_id,
+                    self.entity,
+                    self.location,
+                    change_tick,
+                );
+            }
+        }
+        if let Some(mut despawned) = world.entity_despawned_events() {
+            despawned.send(EntityDespawned {
+                entity: self.entity,
+                tick: change_tick,
+                components: archetype.components().collect(),
+            });
         }
 
         let location = world
@@ -2380,14 +2529,12 @@ fn sorted_remove<T: Eq + Ord + Copy>(source: &mut Vec<T>, remove: &[T]) {
 pub(crate) unsafe fn take_component<'a>(
     storages: &'a mut Storages,
     components: &Components,
-    removed_components: &mut RemovedComponentEvents,
     component_id: ComponentId,
     entity: Entity,
     location: EntityLocation,
 ) -> OwningPtr<'a> {
     // SAFETY: caller promises component_id to be valid
     let component_info = unsafe { components.get_info_unchecked(component_id) };
-    removed_components.send(component_id, entity);
     match component_info.storage_type() {
         StorageType::Table => {
             let table = &mut storages.tables[location.table_id];
@@ -2411,12 +2558,50 @@ pub(crate) unsafe fn take_component<'a>(
     }
 }
 
+/// Sends a removal event for `component_id` on `entity`, capturing a copy of the removed value
+/// if the component registered a [`ComponentRemovedValueFn`](crate::component::ComponentRemovedValueFn).
+///
+/// # Safety
+/// - `location` must be the current location of `entity`
+/// - `component_id` must be valid
+/// - the component must not have been dropped or moved out of its storage yet
+#[inline]
+unsafe fn send_removed_component(
+    storages: &Storages,
+    components: &Components,
+    removed_components: &mut RemovedComponentEvents,
+    component_id: ComponentId,
+    entity: Entity,
+    location: EntityLocation,
+    change_tick: Tick,
+) {
+    // SAFETY: caller promises component_id to be valid
+    let component_info = unsafe { components.get_info_unchecked(component_id) };
+    let value = component_info.removed_value().and_then(|removed_value| {
+        let component = match component_info.storage_type() {
+            StorageType::Table => storages.tables[location.table_id]
+                .get_column(component_id)?
+                .get_data(location.table_row)?,
+            StorageType::SparseSet => storages.sparse_sets.get(component_id)?.get(entity)?,
+        };
+        // SAFETY: `component` points to a value of the type described by `component_info`
+        Some(unsafe { removed_value(component) })
+    });
+    removed_components.send_with_value(component_id, entity, change_tick, value);
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_ptr::OwningPtr;
     use std::panic::AssertUnwindSafe;
 
-    use crate::{self as bevy_ecs, component::ComponentId, prelude::*, system::assert_is_system};
+    use crate::{
+        self as bevy_ecs,
+        component::{ComponentId, StorageType},
+        prelude::*,
+        removal_detection::EntityDespawned,
+        system::assert_is_system,
+    };
 
     #[test]
     fn sorted_remove() {
@@ -2463,6 +2648,94 @@ mod tests {
         assert_eq!(test_component.0, 42);
     }
 
+    #[test]
+    fn entity_ref_get_change_ticks_by_id() {
+        let mut world = World::new();
+        let entity = world.spawn(TestComponent(42)).id();
+        world.increment_change_tick();
+        world.get_mut::<TestComponent>(entity).unwrap().0 = 43;
+        let component_id = world.init_component::<TestComponent>();
+        let missing_id = world.init_component::<TestComponent2>();
+
+        let entity = world.entity(entity);
+        let ticks = entity.get_change_ticks_by_id(component_id).unwrap();
+        let typed_ticks = entity.get_change_ticks::<TestComponent>().unwrap();
+        assert_eq!(ticks.added_tick(), typed_ticks.added_tick());
+        assert_eq!(ticks.last_changed_tick(), typed_ticks.last_changed_tick());
+        assert_ne!(ticks.added_tick(), ticks.last_changed_tick());
+        assert!(entity.get_change_ticks_by_id(missing_id).is_none());
+    }
+
+    #[test]
+    fn entity_ref_changed_components_since() {
+        let mut world = World::new();
+        let entity = world.spawn((TestComponent(0), TestComponent2(0))).id();
+        let component_id = world.init_component::<TestComponent>();
+        let since = world.change_tick();
+        world.increment_change_tick();
+
+        assert_eq!(
+            0,
+            world.entity(entity).changed_components_since(since).count()
+        );
+
+        world.get_mut::<TestComponent>(entity).unwrap().0 = 1;
+        let changed: Vec<_> = world
+            .entity(entity)
+            .changed_components_since(since)
+            .collect();
+        assert_eq!(vec![component_id], changed);
+    }
+
+    #[test]
+    fn despawn_sends_entity_despawned() {
+        let mut world = World::new();
+        let untracked = world.spawn(TestComponent(0)).id();
+        world.despawn(untracked);
+
+        world.init_resource::<Events<EntityDespawned>>();
+        let entity = world.spawn((TestComponent(0), TestComponent2(0))).id();
+        let tick = world.change_tick();
+        world.despawn(entity);
+
+        let events: Vec<_> = world
+            .resource_mut::<Events<EntityDespawned>>()
+            .drain()
+            .collect();
+        assert_eq!(1, events.len());
+        assert_eq!(entity, events[0].entity);
+        assert_eq!(tick, events[0].tick);
+        let mut components = events[0].components.clone();
+        components.sort();
+        let mut expected = vec![
+            world.init_component::<TestComponent>(),
+            world.init_component::<TestComponent2>(),
+        ];
+        expected.sort();
+        assert_eq!(expected, components);
+    }
+
+    #[test]
+    fn commands_and_clear_send_entity_despawned() {
+        let mut world = World::new();
+        assert!(world.entity_despawned_events.is_none());
+        world.init_resource::<Events<EntityDespawned>>();
+        assert!(world.entity_despawned_events.is_some());
+
+        let [a, b, c] = std::array::from_fn(|_| world.spawn(TestComponent(0)).id());
+        world.commands().entity(a).despawn();
+        world.flush_commands();
+        world.clear_entities();
+
+        let mut despawned: Vec<_> = world
+            .resource_mut::<Events<EntityDespawned>>()
+            .drain()
+            .map(|event| event.entity)
+            .collect();
+        despawned.sort();
+        assert_eq!(vec![a, b, c], despawned);
+    }
+
     #[test]
     fn entity_mut_get_by_id() {
         let mut world = World::new();
@@ -2534,6 +2807,34 @@ mod tests {
         assert_ne!(entity.location(), old_location);
     }
 
+    #[test]
+    fn entity_mut_take_panicking_removed_value() {
+        #[derive(Component, Debug, PartialEq)]
+        struct Moved(u32);
+
+        #[derive(Component)]
+        #[component(removed_value)]
+        struct PanicOnClone;
+
+        impl Clone for PanicOnClone {
+            fn clone(&self) -> Self {
+                panic!("cloning the removed value panics");
+            }
+        }
+
+        let mut world = World::new();
+        let id = world.spawn((Moved(1), PanicOnClone)).id();
+        let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
+            world.entity_mut(id).take::<(Moved, PanicOnClone)>();
+        }));
+        assert!(res.is_err());
+
+        // Nothing was moved out of storage before the panic.
+        let entity = world.entity(id);
+        assert_eq!(entity.get::<Moved>(), Some(&Moved(1)));
+        assert!(entity.contains::<PanicOnClone>());
+    }
+
     // regression test for https://github.com/bevyengine/bevy/pull/7805
     #[test]
     fn removing_sparse_updates_archetype_row() {
@@ -2726,6 +3027,99 @@ mod tests {
         assert_eq!(world.entity(e2).get::<Dense>().unwrap(), &Dense(1));
     }
 
+    #[test]
+    fn entity_mut_mark_changed_by_id() {
+        let mut world = World::new();
+        let component_id = world.init_component::<TestComponent>();
+        let entity = world.spawn(TestComponent(42)).id();
+        let spawned = world
+            .entity(entity)
+            .get_ref::<TestComponent>()
+            .unwrap()
+            .last_changed();
+
+        world.increment_change_tick();
+        world.entity_mut(entity).mark_changed_by_id(component_id);
+
+        let component = world.entity(entity).get_ref::<TestComponent>().unwrap();
+        assert!(component
+            .last_changed()
+            .is_newer_than(spawned, world.read_change_tick()));
+        assert_eq!(*component, TestComponent(42));
+    }
+
+    #[test]
+    fn entity_mut_insert_unchanged() {
+        let mut world = World::new();
+        let entity = world.spawn(TestComponent(0)).id();
+        world.clear_trackers();
+        let spawned = world
+            .entity(entity)
+            .get_change_ticks::<TestComponent>()
+            .unwrap();
+
+        world.increment_change_tick();
+        world
+            .entity_mut(entity)
+            .insert_unchanged((TestComponent(1), TestComponent2(1)));
+
+        let entity = world.entity(entity);
+        let component = entity.get_ref::<TestComponent>().unwrap();
+        assert_eq!(*component, TestComponent(1));
+        assert!(!component.is_changed());
+        assert_eq!(component.last_changed(), spawned.last_changed_tick());
+
+        let sparse = entity.get_ref::<TestComponent2>().unwrap();
+        assert_eq!(*sparse, TestComponent2(1));
+        assert!(!sparse.is_added());
+        assert!(!sparse.is_changed());
+    }
+
+    #[derive(Component, Default, Debug, PartialEq)]
+    #[component(register_default)]
+    struct DefaultConstructible(u32);
+
+    #[test]
+    fn entity_mut_insert_default_by_id() {
+        let mut world = World::new();
+        let component_id = world.init_component::<DefaultConstructible>();
+        assert!(world
+            .components()
+            .get_info(component_id)
+            .unwrap()
+            .default_constructor()
+            .is_some());
+
+        let entity = world.spawn_empty().insert_default_by_id(component_id).id();
+        assert_eq!(
+            world.get::<DefaultConstructible>(entity),
+            Some(&DefaultConstructible(0))
+        );
+    }
+
+    #[test]
+    fn entity_mut_insert_default_by_id_manual_impl() {
+        struct Manual(u32);
+
+        impl Component for Manual {
+            const STORAGE_TYPE: StorageType = StorageType::Table;
+            const DEFAULT_CONSTRUCTOR: Option<fn() -> Self> = Some(|| Manual(7));
+        }
+
+        let mut world = World::new();
+        let component_id = world.init_component::<Manual>();
+        let entity = world.spawn_empty().insert_default_by_id(component_id).id();
+        assert_eq!(world.get::<Manual>(entity).map(|manual| manual.0), Some(7));
+    }
+
+    #[test]
+    #[should_panic]
+    fn entity_mut_insert_default_by_id_without_constructor() {
+        let mut world = World::new();
+        let component_id = world.init_component::<TestComponent>();
+        world.spawn_empty().insert_default_by_id(component_id);
+    }
+
     #[test]
     fn entity_mut_insert_by_id() {
         let mut world = World::new();
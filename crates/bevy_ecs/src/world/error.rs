@@ -2,7 +2,7 @@
 
 use thiserror::Error;
 
-use crate::schedule::InternedScheduleLabel;
+use crate::{entity::Entity, schedule::InternedScheduleLabel};
 
 /// The error type returned by [`World::try_run_schedule`] if the provided schedule does not exist.
 ///
@@ -10,3 +10,16 @@ use crate::schedule::InternedScheduleLabel;
 #[derive(Error, Debug)]
 #[error("The schedule with the label {0:?} was not found.")]
 pub struct TryRunScheduleError(pub InternedScheduleLabel);
+
+/// The error type returned by [`World::spawn_at`] if the requested id is already in use.
+///
+/// [`World::spawn_at`]: crate::world::World::spawn_at
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpawnAtError {
+    /// An entity with this exact id already exists.
+    #[error("The entity {0:?} already exists.")]
+    AlreadyExists(Entity),
+    /// A live entity has the same index as this entity, but a different generation.
+    #[error("The index of entity {0:?} is used by a live entity with a different generation.")]
+    IndexInUse(Entity),
+}
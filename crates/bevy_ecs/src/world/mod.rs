@@ -4,6 +4,7 @@ mod command_queue;
 mod deferred_world;
 mod entity_ref;
 pub mod error;
+mod replication;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
@@ -14,12 +15,13 @@ pub use entity_ref::{
     EntityMut, EntityRef, EntityWorldMut, Entry, FilteredEntityMut, FilteredEntityRef,
     OccupiedEntry, VacantEntry,
 };
+pub use replication::{ReplicationMetadata, ReplicationRegistry, ReplicationRule, SendCondition};
 pub use spawn_batch::*;
 
 use crate::{
     archetype::{ArchetypeComponentId, ArchetypeId, ArchetypeRow, Archetypes},
     bundle::{Bundle, BundleInfo, BundleInserter, BundleSpawner, Bundles},
-    change_detection::{MutUntyped, TicksMut},
+    change_detection::{ChangeTicksSnapshot, MutUntyped, TicksMut},
     component::{
         Component, ComponentDescriptor, ComponentHooks, ComponentId, ComponentInfo, ComponentTicks,
         Components, Tick,
@@ -27,16 +29,17 @@ use crate::{
     entity::{AllocAtWithoutReplacement, Entities, Entity, EntityLocation},
     event::{Event, EventId, Events, SendBatchIds},
     query::{DebugCheckedUnwrap, QueryData, QueryEntityError, QueryFilter, QueryState},
-    removal_detection::RemovedComponentEvents,
+    removal_detection::{EntityDespawned, RemovedComponentEvents},
     schedule::{Schedule, ScheduleLabel, Schedules},
     storage::{ResourceData, Storages},
     system::{Commands, Res, Resource},
-    world::error::TryRunScheduleError,
+    world::error::{SpawnAtError, TryRunScheduleError},
 };
 use bevy_ptr::{OwningPtr, Ptr};
-use bevy_utils::tracing::warn;
+use bevy_utils::{tracing::warn, HashMap};
 use std::{
     any::TypeId,
+    borrow::Cow,
     fmt,
     mem::MaybeUninit,
     sync::atomic::{AtomicU32, Ordering},
@@ -112,7 +115,12 @@ pub struct World {
     pub(crate) change_tick: AtomicU32,
     pub(crate) last_change_tick: Tick,
     pub(crate) last_check_tick: Tick,
+    pub(crate) change_detection_enabled: bool,
+    pub(crate) tick_baselines: HashMap<Cow<'static, str>, Tick>,
     pub(crate) command_queue: CommandQueue,
+    /// The [`ComponentId`] of the [`Events<EntityDespawned>`] resource, set once it is registered.
+    /// Despawns only look the resource up when this is set.
+    pub(crate) entity_despawned_events: Option<ComponentId>,
 }
 
 impl Default for World {
@@ -130,7 +138,10 @@ impl Default for World {
             change_tick: AtomicU32::new(1),
             last_change_tick: Tick::new(0),
             last_check_tick: Tick::new(0),
+            change_detection_enabled: true,
+            tick_baselines: HashMap::new(),
             command_queue: CommandQueue::default(),
+            entity_despawned_events: None,
         }
     }
 }
@@ -292,6 +303,32 @@ impl World {
         self.components.component_id::<T>()
     }
 
+    /// Returns `true` if change detection is enabled for the [`Component`] `T`,
+    /// meaning its mutations record change ticks and it can be used in
+    /// [`Added`](crate::query::Added) and [`Changed`](crate::query::Changed) filters.
+    ///
+    /// Generic code such as inspectors or replication can use this to decide whether
+    /// change detection filters can be built for `T`.
+    /// See [`Component::CHANGE_DETECTION_ENABLED`] for more information.
+    #[inline]
+    pub fn is_change_tracked<T: Component>() -> bool {
+        T::CHANGE_DETECTION_ENABLED
+    }
+
+    /// Returns `true` if change detection is enabled for the component or resource with the given id,
+    /// or `None` if no such component or resource exists in this world.
+    ///
+    /// This tells whether mutations of the component or resource record change ticks.
+    /// Components without change detection cannot be used in change detection filters.
+    ///
+    /// This is the dynamic counterpart of [`World::is_change_tracked`].
+    #[inline]
+    pub fn is_change_tracked_by_id(&self, component_id: ComponentId) -> Option<bool> {
+        self.components
+            .get_info(component_id)
+            .map(ComponentInfo::change_detection_enabled)
+    }
+
     /// Retrieves an [`EntityRef`] that exposes read-only operations for the given `entity`.
     /// This will panic if the `entity` does not exist. Use [`World::get_entity`] if you want
     /// to check for entity existence instead of implicitly panic-ing.
@@ -800,8 +837,59 @@ impl World {
     /// ```
     pub fn spawn<B: Bundle>(&mut self, bundle: B) -> EntityWorldMut {
         self.flush_entities();
-        let change_tick = self.change_tick();
         let entity = self.entities.alloc();
+        // SAFETY: entity was just allocated
+        unsafe { self.spawn_with_entity(entity, bundle) }
+    }
+
+    /// Spawns a new [`Entity`] with the exact id `entity` and the given [`Bundle`].
+    ///
+    /// This lets snapshots or authoritative server state be restored with their original ids,
+    /// avoiding a remapping pass. Returns an error if `entity`, or another entity with the same
+    /// index, is alive.
+    ///
+    /// # Note
+    /// Spawning a specific `entity` value is rarely the right choice. Most apps should favor [`World::spawn`].
+    /// Like [`World::get_or_spawn`], this should only be used when ids are guaranteed to be consistent upstream.
+    ///
+    /// ```
+    /// use bevy_ecs::{component::Component, entity::Entity, world::{error::SpawnAtError, World}};
+    ///
+    /// #[derive(Component)]
+    /// struct Num(u32);
+    ///
+    /// let mut world = World::new();
+    /// let entity = Entity::from_raw(42);
+    /// world.spawn_at(entity, Num(1)).unwrap();
+    /// assert_eq!(world.get::<Num>(entity).unwrap().0, 1);
+    ///
+    /// assert_eq!(
+    ///     world.spawn_at(entity, Num(2)).err(),
+    ///     Some(SpawnAtError::AlreadyExists(entity))
+    /// );
+    /// ```
+    pub fn spawn_at<B: Bundle>(
+        &mut self,
+        entity: Entity,
+        bundle: B,
+    ) -> Result<EntityWorldMut, SpawnAtError> {
+        self.flush_entities();
+        match self.entities.alloc_at_without_replacement(entity) {
+            AllocAtWithoutReplacement::Exists(_) => Err(SpawnAtError::AlreadyExists(entity)),
+            AllocAtWithoutReplacement::ExistsWithWrongGeneration => {
+                Err(SpawnAtError::IndexInUse(entity))
+            }
+            AllocAtWithoutReplacement::DidNotExist => {
+                // SAFETY: entity was just allocated
+                Ok(unsafe { self.spawn_with_entity(entity, bundle) })
+            }
+        }
+    }
+
+    /// # Safety
+    /// must be called on an entity that was just allocated
+    unsafe fn spawn_with_entity<B: Bundle>(&mut self, entity: Entity, bundle: B) -> EntityWorldMut {
+        let change_tick = self.change_tick();
         let entity_location = {
             let mut bundle_spawner = BundleSpawner::new::<B>(self, change_tick);
             // SAFETY: bundle's type matches `bundle_info`, entity is allocated but non-existent
@@ -983,6 +1071,156 @@ impl World {
         self.last_change_tick = self.increment_change_tick();
     }
 
+    /// Captures the change ticks of the given components on the given entities.
+    ///
+    /// Components missing from an entity, and entities that do not exist, are skipped.
+    /// The snapshot can later be applied with [`World::restore_change_ticks`].
+    pub fn snapshot_change_ticks(
+        &self,
+        entities: impl IntoIterator<Item = Entity>,
+        component_ids: &[ComponentId],
+    ) -> ChangeTicksSnapshot {
+        let change_tick = self.read_change_tick();
+        let mut snapshot = ChangeTicksSnapshot::default();
+        for entity in entities {
+            let Some(entity_ref) = self.get_entity(entity) else {
+                continue;
+            };
+            for &component_id in component_ids {
+                if let Some(ticks) = entity_ref.get_change_ticks_by_id(component_id) {
+                    snapshot.push(entity, component_id, ticks, change_tick);
+                }
+            }
+        }
+        snapshot
+    }
+
+    /// Restores the change ticks captured by [`World::snapshot_change_ticks`].
+    ///
+    /// Ticks are remapped relative to the current change tick: a component changed `n` ticks before
+    /// the snapshot was taken is marked as changed `n` ticks ago.
+    /// Components that no longer exist on their entity are skipped.
+    pub fn restore_change_ticks(&mut self, snapshot: &ChangeTicksSnapshot) {
+        let change_tick = self.change_tick();
+        for entry in &snapshot.entries {
+            let Some(mut entity) = self.get_entity_mut(entry.entity) else {
+                continue;
+            };
+            let Some(mut component) = entity.get_mut_by_id(entry.component_id) else {
+                continue;
+            };
+            *component.ticks.added = Tick::new(change_tick.get().wrapping_sub(entry.added_age));
+            *component.ticks.changed = Tick::new(change_tick.get().wrapping_sub(entry.changed_age));
+        }
+    }
+
+    /// Creates a named change tick baseline, and returns its tick.
+    ///
+    /// A baseline lets a consumer, like saving or replication, track changes on its own schedule
+    /// rather than relying on the last run of a system. Compare values against it with
+    /// [`DetectChanges::is_changed_since`], and move it forward with [`World::advance_tick_baseline`]
+    /// once the changes have been handled.
+    ///
+    /// Like a system that never ran, a new baseline reports every existing value as changed.
+    /// If a baseline with this name already exists, it is left untouched.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Position(u32);
+    /// let mut world = World::new();
+    /// let entity = world.spawn(Position(0)).id();
+    /// world.create_tick_baseline("replication");
+    ///
+    /// let replicate = |world: &mut World| {
+    ///     let baseline = world.tick_baseline("replication").unwrap();
+    ///     let mut query = world.query::<Ref<Position>>();
+    ///     let changed = query
+    ///         .iter(world)
+    ///         .filter(|position| position.is_changed_since(baseline))
+    ///         .count();
+    ///     world.advance_tick_baseline("replication");
+    ///     changed
+    /// };
+    ///
+    /// assert_eq!(replicate(&mut world), 1);
+    /// assert_eq!(replicate(&mut world), 0);
+    /// world.get_mut::<Position>(entity).unwrap().0 = 1;
+    /// assert_eq!(replicate(&mut world), 1);
+    /// ```
+    ///
+    /// [`DetectChanges::is_changed_since`]: crate::change_detection::DetectChanges::is_changed_since
+    pub fn create_tick_baseline(&mut self, name: impl Into<Cow<'static, str>>) -> Tick {
+        let tick = Tick::expired(self.change_tick());
+        *self.tick_baselines.entry(name.into()).or_insert(tick)
+    }
+
+    /// Returns the tick of the named baseline, if it was created with [`World::create_tick_baseline`].
+    #[inline]
+    pub fn tick_baseline(&self, name: &str) -> Option<Tick> {
+        self.tick_baselines.get(name).copied()
+    }
+
+    /// Moves the named baseline to the current change tick, so that only changes made
+    /// afterwards are reported against it. Returns the previous tick of the baseline,
+    /// or `None` if no baseline with this name exists.
+    pub fn advance_tick_baseline(&mut self, name: &str) -> Option<Tick> {
+        if !self.tick_baselines.contains_key(name) {
+            return None;
+        }
+        // Changes made after this call must land on a newer tick than the baseline.
+        let tick = self.change_tick();
+        self.increment_change_tick();
+        self.tick_baselines
+            .get_mut(name)
+            .map(|baseline| std::mem::replace(baseline, tick))
+    }
+
+    /// Removes the named baseline, returning its tick if it existed.
+    pub fn remove_tick_baseline(&mut self, name: &str) -> Option<Tick> {
+        self.tick_baselines.remove(name)
+    }
+
+    /// Clears the change detection state of every `T` component, leaving other components untouched.
+    ///
+    /// Afterwards, no system observes any `T` as added or changed until it is modified again,
+    /// which lets systems consuming change information acknowledge it explicitly.
+    /// The previous ticks are discarded, so [`DetectChanges::last_changed`] no longer reports them.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Position(f32);
+    /// # #[derive(Component)]
+    /// # struct Velocity(f32);
+    /// let mut world = World::new();
+    /// let entity = world.spawn((Position(0.0), Velocity(0.0))).id();
+    ///
+    /// world.clear_trackers_for::<Position>();
+    ///
+    /// let entity = world.entity(entity);
+    /// assert!(!entity.get_ref::<Position>().unwrap().is_added());
+    /// assert!(entity.get_ref::<Velocity>().unwrap().is_added());
+    /// ```
+    ///
+    /// [`DetectChanges::last_changed`]: crate::change_detection::DetectChanges::last_changed
+    pub fn clear_trackers_for<T: Component>(&mut self) {
+        if let Some(component_id) = self.component_id::<T>() {
+            self.clear_trackers_for_id(component_id);
+        }
+    }
+
+    /// Clears the change detection state of the component with the given `component_id`.
+    ///
+    /// See [`World::clear_trackers_for`] for more details.
+    pub fn clear_trackers_for_id(&mut self, component_id: ComponentId) {
+        let expired = Tick::expired(self.change_tick());
+        self.storages.tables.set_all_ticks(component_id, expired);
+        if let Some(sparse_set) = self.storages.sparse_sets.get_mut(component_id) {
+            sparse_set.set_all_ticks(expired);
+        }
+    }
+
     /// Returns [`QueryState`] for the given [`QueryData`], which is used to efficiently
     /// run queries on the [`World`] by storing and reusing the [`QueryState`].
     /// ```
@@ -1300,9 +1538,11 @@ impl World {
             .resources
             .get(component_id)
             .and_then(|resource| {
-                resource
-                    .get_ticks()
-                    .map(|ticks| ticks.is_changed(self.last_change_tick(), self.read_change_tick()))
+                resource.get_ticks().map(|ticks| {
+                    // Resources without change detection can't prove they are unchanged.
+                    !resource.change_detection_enabled()
+                        || ticks.is_changed(self.last_change_tick(), self.read_change_tick())
+                })
             })
             .unwrap_or(false)
     }
@@ -1710,6 +1950,7 @@ impl World {
                 changed: &mut ticks.changed,
                 last_run: last_change_tick,
                 this_run: change_tick,
+                tracks_changes: R::CHANGE_DETECTION_ENABLED,
             },
         };
         let result = f(self, value_mut);
@@ -1827,6 +2068,15 @@ impl World {
         &mut self,
         component_id: ComponentId,
     ) -> &mut ResourceData<true> {
+        if self.entity_despawned_events.is_none()
+            && self
+                .components
+                .get_info(component_id)
+                .and_then(ComponentInfo::type_id)
+                == Some(TypeId::of::<Events<EntityDespawned>>())
+        {
+            self.entity_despawned_events = Some(component_id);
+        }
         let archetypes = &mut self.archetypes;
         self.storages
             .resources
@@ -1835,6 +2085,15 @@ impl World {
             })
     }
 
+    /// Returns the [`Events<EntityDespawned>`] resource if it exists, without looking up its type.
+    #[inline]
+    pub(crate) fn entity_despawned_events(&mut self) -> Option<Mut<'_, Events<EntityDespawned>>> {
+        let component_id = self.entity_despawned_events?;
+        let events = self.get_resource_mut_by_id(component_id)?;
+        // SAFETY: `entity_despawned_events` is only set to the id of `Events<EntityDespawned>`.
+        Some(unsafe { events.with_type::<Events<EntityDespawned>>() })
+    }
+
     /// # Panics
     /// Panics if `component_id` is not registered in this world
     #[inline]
@@ -1915,6 +2174,42 @@ impl World {
         self.last_change_tick
     }
 
+    /// Returns the [`Tick`] at which [`World::check_change_ticks`] last clamped the stored change ticks.
+    ///
+    /// The next pass will only do work once the world's change tick has advanced at least
+    /// [`CHECK_TICK_THRESHOLD`] ticks past this value. Applications that do not run schedules,
+    /// or that drive the world manually for long periods of time, can use this to decide when
+    /// to call [`World::check_change_ticks`] themselves.
+    #[inline]
+    pub fn last_check_tick(&self) -> Tick {
+        self.last_check_tick
+    }
+
+    /// Returns `true` if [`Added`] and [`Changed`] filters compare change ticks in this world.
+    ///
+    /// See [`World::set_change_detection_enabled`].
+    ///
+    /// [`Added`]: crate::query::Added
+    /// [`Changed`]: crate::query::Changed
+    #[inline]
+    pub fn is_change_detection_enabled(&self) -> bool {
+        self.change_detection_enabled
+    }
+
+    /// Turns change detection filtering on or off for the whole world.
+    ///
+    /// While turned off, [`Added`] and [`Changed`] filters skip their tick comparisons and
+    /// treat every component as added and changed. This suits headless servers and benchmarks
+    /// that never rely on change detection. Change ticks are still recorded on mutation,
+    /// so filtering behaves as usual once change detection is turned back on.
+    ///
+    /// [`Added`]: crate::query::Added
+    /// [`Changed`]: crate::query::Changed
+    #[inline]
+    pub fn set_change_detection_enabled(&mut self, enabled: bool) {
+        self.change_detection_enabled = enabled;
+    }
+
     /// Sets [`World::last_change_tick()`] to the specified value during a scope.
     /// When the scope terminates, it will return to its old value.
     ///
@@ -2030,7 +2325,10 @@ impl World {
     /// This prevents overflow and thus prevents false positives.
     ///
     /// **Note:** Does nothing if the [`World`] counter has not been incremented at least [`CHECK_TICK_THRESHOLD`]
-    /// times since the previous pass.
+    /// times since the previous pass, see [`World::last_check_tick`].
+    ///
+    /// This is called automatically when running a [`Schedule`]. Worlds that are updated without running
+    /// schedules must call it periodically to avoid tick aliasing.
     // TODO: benchmark and optimize
     pub fn check_change_ticks(&mut self) {
         let change_tick = self.change_tick();
@@ -2056,6 +2354,10 @@ impl World {
             schedules.check_change_ticks(change_tick);
         }
 
+        for baseline in self.tick_baselines.values_mut() {
+            baseline.check_tick(change_tick);
+        }
+
         self.last_check_tick = change_tick;
     }
 
@@ -2067,7 +2369,30 @@ impl World {
     }
 
     /// Despawns all entities in this [`World`].
+    ///
+    /// Like [`World::despawn`], this sends an [`EntityDespawned`] event for every entity
+    /// if the [`Events<EntityDespawned>`] resource exists.
     pub fn clear_entities(&mut self) {
+        if self.entity_despawned_events.is_some() {
+            let tick = self.change_tick();
+            let despawned: Vec<EntityDespawned> = self
+                .archetypes
+                .iter()
+                .flat_map(|archetype| {
+                    archetype
+                        .entities()
+                        .iter()
+                        .map(move |entity| EntityDespawned {
+                            entity: entity.id(),
+                            tick,
+                            components: archetype.components().collect(),
+                        })
+                })
+                .collect();
+            if let Some(mut events) = self.entity_despawned_events() {
+                events.send_batch(despawned);
+            }
+        }
         self.storages.tables.clear();
         self.storages.sparse_sets.clear_entities();
         self.archetypes.clear_entities();
@@ -2325,7 +2650,8 @@ impl World {
                         self.last_change_tick(),
                         self.read_change_tick(),
                     )
-                };
+                }
+                .with_change_detection(data.change_detection_enabled());
 
                 let mut_untyped = MutUntyped {
                     // SAFETY:
@@ -2619,11 +2945,13 @@ impl<T: Default> FromWorld for T {
 
 #[cfg(test)]
 mod tests {
-    use super::{FromWorld, World};
+    use super::{error::SpawnAtError, FromWorld, World};
     use crate::{
-        change_detection::DetectChangesMut,
-        component::{ComponentDescriptor, ComponentInfo, StorageType},
+        change_detection::{DetectChanges, DetectChangesMut},
+        component::{ComponentDescriptor, ComponentId, ComponentInfo, StorageType},
+        entity::Entity,
         ptr::OwningPtr,
+        query::{Added, Changed, Or},
         system::Resource,
     };
     use bevy_ecs_macros::Component;
@@ -2727,6 +3055,159 @@ mod tests {
         }
     }
 
+    #[test]
+    fn is_change_tracked() {
+        #[derive(Component)]
+        struct Tracked;
+
+        #[derive(Component)]
+        #[component(change_detection = false)]
+        struct Untracked;
+
+        #[derive(Resource, Default)]
+        #[resource(change_detection = false)]
+        struct UntrackedResource;
+
+        let mut world = World::new();
+        assert!(World::is_change_tracked::<Tracked>());
+        assert!(!World::is_change_tracked::<Untracked>());
+
+        let tracked = world.init_component::<Tracked>();
+        let untracked = world.init_component::<Untracked>();
+        let untracked_resource = world.init_resource::<UntrackedResource>();
+        assert_eq!(world.is_change_tracked_by_id(tracked), Some(true));
+        assert_eq!(world.is_change_tracked_by_id(untracked), Some(false));
+        assert_eq!(
+            world.is_change_tracked_by_id(untracked_resource),
+            Some(false)
+        );
+        assert_eq!(
+            world.is_change_tracked_by_id(ComponentId::new(usize::MAX)),
+            None
+        );
+    }
+
+    #[test]
+    fn snapshot_and_restore_change_ticks() {
+        #[derive(Component)]
+        struct Position(u32);
+
+        let mut world = World::new();
+        let entity = world.spawn(Position(0)).id();
+        let empty = world.spawn_empty().id();
+        let position = world.component_id::<Position>().unwrap();
+        for _ in 0..10 {
+            world.increment_change_tick();
+        }
+        world.get_mut::<Position>(entity).unwrap().0 = 1;
+        world.increment_change_tick();
+
+        let snapshot_tick = world.change_tick();
+        let before = world.entity(entity).get_change_ticks::<Position>().unwrap();
+        let snapshot = world.snapshot_change_ticks([entity, empty], &[position]);
+        assert_eq!(snapshot.len(), 1);
+
+        for _ in 0..100 {
+            world.increment_change_tick();
+        }
+        world.get_mut::<Position>(entity).unwrap().0 = 2;
+        world.restore_change_ticks(&snapshot);
+
+        let restore_tick = world.change_tick();
+        let after = world.entity(entity).get_change_ticks::<Position>().unwrap();
+        assert_eq!(
+            restore_tick.relative_to(after.added),
+            snapshot_tick.relative_to(before.added)
+        );
+        assert_eq!(
+            restore_tick.relative_to(after.changed),
+            snapshot_tick.relative_to(before.changed)
+        );
+    }
+
+    #[test]
+    fn change_detection_switch() {
+        #[derive(Component)]
+        struct Position(u32);
+
+        let mut world = World::new();
+        let entity = world.spawn(Position(0)).id();
+        world.clear_trackers();
+
+        let mut added = world.query_filtered::<Entity, Added<Position>>();
+        let mut changed = world.query_filtered::<Entity, Changed<Position>>();
+        assert_eq!(added.iter(&world).count(), 0);
+        assert_eq!(changed.iter(&world).count(), 0);
+
+        world.set_change_detection_enabled(false);
+        assert!(!world.is_change_detection_enabled());
+        assert_eq!(added.iter(&world).collect::<Vec<_>>(), vec![entity]);
+        assert_eq!(changed.iter(&world).collect::<Vec<_>>(), vec![entity]);
+
+        world.set_change_detection_enabled(true);
+        assert_eq!(added.iter(&world).count(), 0);
+        assert_eq!(changed.iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn tick_baselines() {
+        #[derive(Component)]
+        struct Position(u32);
+
+        let mut world = World::new();
+        let entity = world.spawn(Position(0)).id();
+        let save = world.create_tick_baseline("save");
+        let replication = world.create_tick_baseline("replication");
+        assert_eq!(world.create_tick_baseline("save"), save);
+
+        let is_changed_since = |world: &World, name: &str| {
+            let baseline = world.tick_baseline(name).unwrap();
+            let position = world.entity(entity).get_ref::<Position>().unwrap();
+            position.is_changed_since(baseline)
+        };
+        assert!(is_changed_since(&world, "save"));
+        assert!(is_changed_since(&world, "replication"));
+
+        // Advancing one baseline doesn't affect the others.
+        assert_eq!(world.advance_tick_baseline("save"), Some(save));
+        assert!(!is_changed_since(&world, "save"));
+        assert!(is_changed_since(&world, "replication"));
+
+        world.get_mut::<Position>(entity).unwrap().0 = 1;
+        assert!(is_changed_since(&world, "save"));
+
+        assert_eq!(world.remove_tick_baseline("replication"), Some(replication));
+        assert_eq!(world.tick_baseline("replication"), None);
+        assert_eq!(world.advance_tick_baseline("replication"), None);
+    }
+
+    #[test]
+    fn clear_trackers_for() {
+        #[derive(Component)]
+        struct Table;
+
+        #[derive(Component)]
+        #[component(storage = "SparseSet")]
+        struct Sparse;
+
+        #[derive(Component)]
+        struct Other;
+
+        let mut world = World::new();
+        let entity = world.spawn((Table, Sparse, Other)).id();
+        world.clear_trackers();
+        world.entity_mut(entity).insert((Table, Sparse, Other));
+
+        world.clear_trackers_for::<Table>();
+        let sparse = world.component_id::<Sparse>().unwrap();
+        world.clear_trackers_for_id(sparse);
+
+        let mut changed = world.query_filtered::<Entity, Or<(Changed<Table>, Changed<Sparse>)>>();
+        assert_eq!(changed.iter(&world).count(), 0);
+        let mut changed = world.query_filtered::<Entity, Changed<Other>>();
+        assert_eq!(changed.iter(&world).collect::<Vec<_>>(), vec![entity]);
+    }
+
     #[test]
     fn panic_while_overwriting_component() {
         let helper = DropTestHelper::new();
@@ -3002,6 +3483,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn archetypes_with_component() {
+        let mut world = World::new();
+        world.spawn((Foo, Bar));
+        world.spawn((Foo, Bar));
+        world.spawn((Foo, Baz));
+        let bar_only = world.spawn(Bar).id();
+
+        let foo = world.component_id::<Foo>().unwrap();
+        let bar = world.component_id::<Bar>().unwrap();
+        let archetypes = world.archetypes();
+        assert_eq!(2, archetypes.archetypes_with_component(foo).len());
+        assert_eq!(3, archetypes.component_entity_count(foo));
+        assert_eq!(3, archetypes.component_entity_count(bar));
+        assert!(archetypes
+            .archetypes_with_component(bar)
+            .iter()
+            .all(|&id| archetypes[id].contains(bar)));
+
+        world.despawn(bar_only);
+        assert_eq!(2, world.archetypes().component_entity_count(bar));
+        assert_eq!(
+            0,
+            world
+                .archetypes()
+                .archetypes_with_component(ComponentId::new(usize::MAX))
+                .len()
+        );
+    }
+
     #[test]
     fn iterate_entities() {
         let mut world = World::new();
@@ -3124,4 +3635,34 @@ mod tests {
         let mut world = World::new();
         world.spawn(());
     }
+
+    #[test]
+    fn spawn_at() {
+        let mut world = World::new();
+        let entity = Entity::from_raw(5);
+        world.spawn_at(entity, Foo).unwrap();
+        assert!(world.get::<Foo>(entity).is_some());
+        assert_eq!(1, world.entities().len());
+
+        // Lower indices skipped over are still available to `spawn`.
+        let spawned = world.spawn_empty().id();
+        assert!(spawned.index() < 5);
+
+        assert_eq!(
+            Some(SpawnAtError::AlreadyExists(entity)),
+            world.spawn_at(entity, Bar).err()
+        );
+
+        world.despawn(entity);
+        let reused = world.spawn_at(entity, Bar).unwrap().id();
+        assert_eq!(entity, reused);
+
+        world.despawn(spawned);
+        let newer = world.spawn_empty().id();
+        assert_eq!(spawned.index(), newer.index());
+        assert_eq!(
+            Some(SpawnAtError::IndexInUse(spawned)),
+            world.spawn_at(spawned, Foo).err()
+        );
+    }
 }
@@ -0,0 +1,275 @@
+//! Replication metadata for components, used by networking crates to decide what to send.
+
+use crate::{
+    component::{Component, ComponentId, Tick},
+    entity::Entity,
+    storage::SparseSet,
+    system::Resource,
+    world::{EntityRef, World},
+};
+
+/// When a replicated component of an entity should be sent.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SendCondition {
+    /// Send the component when it was added or changed since the last time it was sent.
+    #[default]
+    OnChange,
+    /// Send the component only when it was added since the last time it was sent.
+    OnAdd,
+    /// Send the component every time, whether it changed or not.
+    Always,
+}
+
+/// How a component of type `C` is replicated, registered with [`World::register_replication`].
+pub struct ReplicationRule<C: Component> {
+    serialize: fn(&C, &mut Vec<u8>),
+    priority: u32,
+    send_condition: SendCondition,
+}
+
+impl<C: Component> ReplicationRule<C> {
+    /// Creates a rule serializing the component with `serialize`, with a priority of `0`,
+    /// sent [`OnChange`](SendCondition::OnChange).
+    pub fn new(serialize: fn(&C, &mut Vec<u8>)) -> Self {
+        Self {
+            serialize,
+            priority: 0,
+            send_condition: SendCondition::OnChange,
+        }
+    }
+
+    /// Sets the priority of the component. Components with a higher priority are sent first.
+    #[must_use]
+    pub fn with_priority(mut self, priority: u32) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Sets when the component should be sent.
+    #[must_use]
+    pub fn with_send_condition(mut self, send_condition: SendCondition) -> Self {
+        self.send_condition = send_condition;
+        self
+    }
+}
+
+/// The type-erased replication metadata of a component, stored in a [`ReplicationRegistry`].
+pub struct ReplicationMetadata {
+    serialize: Box<dyn Fn(EntityRef, &mut Vec<u8>) -> bool + Send + Sync>,
+    priority: u32,
+    send_condition: SendCondition,
+}
+
+impl ReplicationMetadata {
+    /// Appends the serialized component of `entity` to `out`.
+    ///
+    /// Returns `false`, leaving `out` untouched, if `entity` doesn't have the component.
+    pub fn serialize(&self, entity: EntityRef, out: &mut Vec<u8>) -> bool {
+        (self.serialize)(entity, out)
+    }
+
+    /// Returns the priority of the component. Components with a higher priority are sent first.
+    pub fn priority(&self) -> u32 {
+        self.priority
+    }
+
+    /// Returns when the component should be sent.
+    pub fn send_condition(&self) -> SendCondition {
+        self.send_condition
+    }
+}
+
+impl<C: Component> From<ReplicationRule<C>> for ReplicationMetadata {
+    fn from(rule: ReplicationRule<C>) -> Self {
+        let serialize = rule.serialize;
+        Self {
+            serialize: Box::new(move |entity, out| {
+                entity
+                    .get::<C>()
+                    .map(|component| serialize(component, out))
+                    .is_some()
+            }),
+            priority: rule.priority,
+            send_condition: rule.send_condition,
+        }
+    }
+}
+
+/// The replication metadata of every replicated component, keyed by [`ComponentId`].
+///
+/// Components are registered with [`World::register_replication`]. Networking crates then use
+/// [`components_to_send`](Self::components_to_send) with the tick they last sent an entity at,
+/// and [`ReplicationMetadata::serialize`] to write the components out.
+#[derive(Resource, Default)]
+pub struct ReplicationRegistry {
+    metadata: SparseSet<ComponentId, ReplicationMetadata>,
+}
+
+impl ReplicationRegistry {
+    /// Registers the replication metadata of the component with the given [`ComponentId`],
+    /// replacing any previous registration.
+    pub fn register(&mut self, component_id: ComponentId, metadata: ReplicationMetadata) {
+        self.metadata.insert(component_id, metadata);
+    }
+
+    /// Stops replicating the component with the given [`ComponentId`], returning its metadata.
+    pub fn unregister(&mut self, component_id: ComponentId) -> Option<ReplicationMetadata> {
+        self.metadata.remove(component_id)
+    }
+
+    /// Returns the replication metadata of the component with the given [`ComponentId`], if it is replicated.
+    pub fn get(&self, component_id: ComponentId) -> Option<&ReplicationMetadata> {
+        self.metadata.get(component_id)
+    }
+
+    /// Returns `true` if the component with the given [`ComponentId`] is replicated.
+    pub fn contains(&self, component_id: ComponentId) -> bool {
+        self.metadata.contains(component_id)
+    }
+
+    /// Iterates over the replicated components and their metadata.
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, &ReplicationMetadata)> {
+        self.metadata.iter().map(|(&id, metadata)| (id, metadata))
+    }
+
+    /// Returns the replicated components of `entity` that should be sent, given that it was
+    /// last sent at `since`, by decreasing priority.
+    ///
+    /// Returns an empty list if `entity` doesn't exist.
+    pub fn components_to_send(
+        &self,
+        world: &World,
+        entity: Entity,
+        since: Tick,
+    ) -> Vec<(ComponentId, &ReplicationMetadata)> {
+        let Some(entity) = world.get_entity(entity) else {
+            return Vec::new();
+        };
+        let this_run = world.read_change_tick();
+        let mut to_send: Vec<_> = entity
+            .archetype()
+            .components()
+            .filter_map(|component_id| {
+                let metadata = self.metadata.get(component_id)?;
+                let ticks = entity.get_change_ticks_by_id(component_id)?;
+                let send = match metadata.send_condition {
+                    SendCondition::OnChange => ticks.is_changed(since, this_run),
+                    SendCondition::OnAdd => ticks.is_added(since, this_run),
+                    SendCondition::Always => true,
+                };
+                send.then_some((component_id, metadata))
+            })
+            .collect();
+        to_send.sort_by_key(|(_, metadata)| std::cmp::Reverse(metadata.priority));
+        to_send
+    }
+}
+
+impl World {
+    /// Registers `C` as a replicated component, following `rule`, in the [`ReplicationRegistry`]
+    /// resource, which is inserted if needed. Returns the [`ComponentId`] of `C`.
+    ///
+    /// Registering the same component again replaces its previous rule.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::world::{ReplicationRegistry, ReplicationRule};
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::new();
+    /// let health = world.register_replication(
+    ///     ReplicationRule::new(|health: &Health, out| out.extend(health.0.to_le_bytes()))
+    ///         .with_priority(10),
+    /// );
+    ///
+    /// let since = world.change_tick();
+    /// world.increment_change_tick();
+    /// let entity = world.spawn(Health(100)).id();
+    ///
+    /// let registry = world.resource::<ReplicationRegistry>();
+    /// let to_send = registry.components_to_send(&world, entity, since);
+    /// assert_eq!(to_send.len(), 1);
+    /// assert_eq!(to_send[0].0, health);
+    /// ```
+    pub fn register_replication<C: Component>(&mut self, rule: ReplicationRule<C>) -> ComponentId {
+        let component_id = self.init_component::<C>();
+        self.get_resource_or_insert_with(ReplicationRegistry::default)
+            .register(component_id, rule.into());
+        component_id
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{component::Component, world::World};
+
+    use super::{ReplicationRegistry, ReplicationRule, SendCondition};
+
+    #[derive(Component)]
+    struct Position(u8);
+
+    #[derive(Component)]
+    struct Kind(u8);
+
+    #[derive(Component)]
+    struct Unreplicated;
+
+    #[test]
+    fn components_to_send_follow_rules() {
+        let mut world = World::new();
+        let position = world.register_replication(
+            ReplicationRule::new(|position: &Position, out| out.push(position.0)).with_priority(1),
+        );
+        let kind = world.register_replication(
+            ReplicationRule::new(|kind: &Kind, out| out.push(kind.0))
+                .with_priority(5)
+                .with_send_condition(SendCondition::OnAdd),
+        );
+
+        let since = world.change_tick();
+        world.increment_change_tick();
+        let entity = world.spawn((Position(1), Kind(2), Unreplicated)).id();
+
+        // Unregistered components are never sent, and higher priorities come first.
+        let registry = world.resource::<ReplicationRegistry>();
+        let to_send = registry.components_to_send(&world, entity, since);
+        let ids: Vec<_> = to_send.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [kind, position]);
+
+        let mut out = Vec::new();
+        for (_, metadata) in &to_send {
+            assert!(metadata.serialize(world.entity(entity), &mut out));
+        }
+        assert_eq!(out, [2, 1]);
+
+        // `Kind` is only sent when added, `Position` whenever it changes.
+        let since = world.change_tick();
+        world.increment_change_tick();
+        world.get_mut::<Position>(entity).unwrap().0 = 3;
+        world.get_mut::<Kind>(entity).unwrap().0 = 4;
+        let registry = world.resource::<ReplicationRegistry>();
+        let to_send = registry.components_to_send(&world, entity, since);
+        let ids: Vec<_> = to_send.iter().map(|(id, _)| *id).collect();
+        assert_eq!(ids, [position]);
+    }
+
+    #[test]
+    fn serialize_skips_missing_components() {
+        let mut world = World::new();
+        let position =
+            world.register_replication(ReplicationRule::new(|position: &Position, out| {
+                out.push(position.0)
+            }));
+        let entity = world.spawn(Unreplicated).id();
+
+        let registry = world.resource::<ReplicationRegistry>();
+        let mut out = Vec::new();
+        assert!(!registry
+            .get(position)
+            .unwrap()
+            .serialize(world.entity(entity), &mut out));
+        assert!(out.is_empty());
+    }
+}
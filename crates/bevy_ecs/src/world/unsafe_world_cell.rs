@@ -262,6 +262,16 @@ impl<'w> UnsafeWorldCell<'w> {
         unsafe { self.world_metadata() }.last_change_tick()
     }
 
+    /// Returns `true` if change detection filters are enabled in this world.
+    ///
+    /// See [`World::is_change_detection_enabled()`].
+    #[inline]
+    pub fn is_change_detection_enabled(self) -> bool {
+        // SAFETY:
+        // - we only access world metadata
+        unsafe { self.world_metadata() }.is_change_detection_enabled()
+    }
+
     /// Increments the world's current change tick and returns the old value.
     #[inline]
     pub fn increment_change_tick(self) -> Tick {
@@ -322,15 +332,13 @@ impl<'w> UnsafeWorldCell<'w> {
 
         // SAFETY: caller ensures `self` has permission to access the resource
         // caller also ensure that no mutable reference to the resource exists
-        let (ptr, ticks) = unsafe { self.get_resource_with_ticks(component_id)? };
+        let (ptr, ticks) = unsafe {
+            self.get_resource_with_ticks(component_id, self.last_change_tick(), self.change_tick())?
+        };
 
         // SAFETY: `component_id` was obtained from the type ID of `R`
         let value = unsafe { ptr.deref::<R>() };
 
-        // SAFETY: caller ensures that no mutable reference to the resource exists
-        let ticks =
-            unsafe { Ticks::from_tick_cells(ticks, self.last_change_tick(), self.change_tick()) };
-
         Some(Res { value, ticks })
     }
 
@@ -434,17 +442,16 @@ impl<'w> UnsafeWorldCell<'w> {
     ) -> Option<MutUntyped<'w>> {
         // SAFETY: we only access data that the caller has ensured is unaliased and `self`
         //  has permission to access.
-        let (ptr, ticks) = unsafe { self.storages() }
-            .resources
-            .get(component_id)?
-            .get_with_ticks()?;
+        let data = unsafe { self.storages() }.resources.get(component_id)?;
+        let (ptr, ticks) = data.get_with_ticks()?;
 
         // SAFETY:
         // - index is in-bounds because the column is initialized and non-empty
         // - the caller promises that no other reference to the ticks of the same row can exist at the same time
         let ticks = unsafe {
             TicksMut::from_tick_cells(ticks, self.last_change_tick(), self.change_tick())
-        };
+        }
+        .with_change_detection(data.change_detection_enabled());
 
         Some(MutUntyped {
             // SAFETY:
@@ -518,21 +525,25 @@ impl<'w> UnsafeWorldCell<'w> {
     ///
     /// # Safety
     /// It is the callers responsibility to ensure that
-    /// - the [`UnsafeWorldCell`] has permission to access the resource mutably
+    /// - the [`UnsafeWorldCell`] has permission to access the resource
     /// - no mutable references to the resource exist at the same time
     #[inline]
     pub(crate) unsafe fn get_resource_with_ticks(
         self,
         component_id: ComponentId,
-    ) -> Option<(Ptr<'w>, TickCells<'w>)> {
+        last_run: Tick,
+        this_run: Tick,
+    ) -> Option<(Ptr<'w>, Ticks<'w>)> {
         // SAFETY:
         // - caller ensures there is no `&mut World`
         // - caller ensures there are no mutable borrows of this resource
         // - caller ensures that we have permission to access this resource
-        unsafe { self.storages() }
-            .resources
-            .get(component_id)?
-            .get_with_ticks()
+        let data = unsafe { self.storages() }.resources.get(component_id)?;
+        let (ptr, ticks) = data.get_with_ticks()?;
+        // SAFETY: caller ensures that no mutable reference to the resource exists
+        let ticks = unsafe { Ticks::from_tick_cells(ticks, last_run, this_run) }
+            .with_change_detection(data.change_detection_enabled());
+        Some((ptr, ticks))
     }
 
     // Shorthand helper function for getting the data and change ticks for a resource.
@@ -715,7 +726,8 @@ impl<'w> UnsafeEntityCell<'w> {
             .map(|(value, cells)| Ref {
                 // SAFETY: returned component is of type T
                 value: value.deref::<T>(),
-                ticks: Ticks::from_tick_cells(cells, last_change_tick, change_tick),
+                ticks: Ticks::from_tick_cells(cells, last_change_tick, change_tick)
+                    .with_change_detection(T::CHANGE_DETECTION_ENABLED),
             })
         }
     }
@@ -814,7 +826,8 @@ impl<'w> UnsafeEntityCell<'w> {
             .map(|(value, cells)| Mut {
                 // SAFETY: returned component is of type T
                 value: value.assert_unique().deref_mut::<T>(),
-                ticks: TicksMut::from_tick_cells(cells, last_change_tick, change_tick),
+                ticks: TicksMut::from_tick_cells(cells, last_change_tick, change_tick)
+                    .with_change_detection(T::CHANGE_DETECTION_ENABLED),
             })
         }
     }
@@ -878,7 +891,8 @@ impl<'w> UnsafeEntityCell<'w> {
                     cells,
                     self.world.last_change_tick(),
                     self.world.change_tick(),
-                ),
+                )
+                .with_change_detection(info.change_detection_enabled()),
             })
         }
     }
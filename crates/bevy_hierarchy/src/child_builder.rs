@@ -1,11 +1,14 @@
+use std::fmt;
+
 use crate::{Children, HierarchyEvent, Parent};
 use bevy_ecs::{
     bundle::Bundle,
-    entity::Entity,
+    entity::{Entity, EntityHashMap, EntityHashSet},
     prelude::Events,
-    system::{Commands, EntityCommands},
+    system::{Commands, EntityCommands, Resource},
     world::{Command, EntityWorldMut, World},
 };
+use bevy_utils::tracing::error;
 use smallvec::{smallvec, SmallVec};
 
 // Do not use `world.send_event_batch` as it prints error message when the Events are not available in the world,
@@ -28,12 +31,108 @@ fn push_child_unchecked(world: &mut World, parent: Entity, child: Entity) {
     }
 }
 
+/// Opts into cycle detection for the hierarchy commands and [`BuildWorldChildren`] methods.
+///
+/// A hierarchy containing a cycle hangs any traversal of it. When this resource exists, adding
+/// children to an entity or setting its parent first walks up the ancestors of the new parent,
+/// and rejects the change if one of the new children is among them. The walk costs O(depth) per
+/// change, so it is disabled unless this resource is inserted.
+#[derive(Resource, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum HierarchyCycleCheck {
+    /// Panic when a change would create a cycle.
+    #[default]
+    Panic,
+    /// Log an error and skip the change when it would create a cycle.
+    Error,
+}
+
+/// A hierarchy change rejected by [`HierarchyCycleCheck`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HierarchyCycleError {
+    /// Adding `child` to `parent` would make `child` its own ancestor.
+    WouldCreateCycle {
+        /// The entity the child was being added to.
+        parent: Entity,
+        /// The child, which is an ancestor of `parent`.
+        child: Entity,
+    },
+    /// The ancestors of `parent` already contain a cycle, so the change can't be checked.
+    ExistingCycle {
+        /// The entity the children were being added to.
+        parent: Entity,
+    },
+}
+
+impl fmt::Display for HierarchyCycleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::WouldCreateCycle { parent, child } => write!(
+                f,
+                "Cannot add {child:?} as a child of its descendant {parent:?}, as it would create a cycle."
+            ),
+            Self::ExistingCycle { parent } => write!(
+                f,
+                "Cannot add children to {parent:?}, as its ancestors already contain a cycle."
+            ),
+        }
+    }
+}
+
+/// Checks that no entity in `children` is an ancestor of `parent`.
+///
+/// Each ancestor is only visited once, so a cycle that already exists is reported instead of
+/// looping forever.
+fn find_cycle(
+    world: &World,
+    parent: Entity,
+    children: &[Entity],
+) -> Result<(), HierarchyCycleError> {
+    let children: EntityHashSet = children.iter().copied().collect();
+    let mut visited = EntityHashSet::default();
+    let mut ancestor = world.get::<Parent>(parent).map(Parent::get);
+    while let Some(entity) = ancestor {
+        if children.contains(&entity) {
+            return Err(HierarchyCycleError::WouldCreateCycle {
+                parent,
+                child: entity,
+            });
+        }
+        if !visited.insert(entity) {
+            return Err(HierarchyCycleError::ExistingCycle { parent });
+        }
+        ancestor = world.get::<Parent>(entity).map(Parent::get);
+    }
+    Ok(())
+}
+
+/// Returns `false` if the change must be skipped because adding `children` to `parent` would
+/// create a cycle, according to the [`HierarchyCycleCheck`] resource.
+fn check_cycles(world: &World, parent: Entity, children: &[Entity]) -> bool {
+    let Some(&check) = world.get_resource::<HierarchyCycleCheck>() else {
+        return true;
+    };
+    let Err(error) = find_cycle(world, parent, children) else {
+        return true;
+    };
+    match check {
+        HierarchyCycleCheck::Panic => panic!("{error}"),
+        HierarchyCycleCheck::Error => {
+            error!("{error}");
+            false
+        }
+    }
+}
+
 /// Sets [`Parent`] of the `child` to `new_parent`. Inserts [`Parent`] if `child` doesn't have one.
+///
+/// [`Parent`] is left untouched if it already points to `new_parent`, so it isn't marked as changed.
 fn update_parent(world: &mut World, child: Entity, new_parent: Entity) -> Option<Entity> {
     let mut child = world.entity_mut(child);
     if let Some(mut parent) = child.get_mut::<Parent>() {
         let previous = parent.0;
-        *parent = Parent(new_parent);
+        if previous != new_parent {
+            *parent = Parent(new_parent);
+        }
         Some(previous)
     } else {
         child.insert(Parent(new_parent));
@@ -57,6 +156,27 @@ fn remove_from_children(world: &mut World, parent: Entity, child: Entity) {
     }
 }
 
+/// Like [`remove_from_children`], for several children of the same parent at once.
+fn remove_all_from_children(world: &mut World, parent: Entity, removed: &EntityHashSet) {
+    let Some(mut parent) = world.get_entity_mut(parent) else {
+        return;
+    };
+    let Some(mut children) = parent.get_mut::<Children>() else {
+        return;
+    };
+    children.0.retain(|x| !removed.contains(x));
+    if children.is_empty() {
+        parent.remove::<Children>();
+    }
+}
+
+/// Removes the [`Parent`] component of every entity in `children`, in a single pass.
+fn remove_parents(world: &mut World, children: impl IntoIterator<Item = Entity>) {
+    for child in children {
+        world.entity_mut(child).remove::<Parent>();
+    }
+}
+
 /// Update the [`Parent`] component of the `child`.
 /// Removes the `child` from the previous parent's [`Children`].
 ///
@@ -93,10 +213,12 @@ fn update_old_parent(world: &mut World, child: Entity, parent: Entity) {
 /// Does not update the new parents [`Children`] component.
 ///
 /// Does nothing for a child if it was already a child of `parent`.
+/// The [`Children`] of each previous parent is only updated once, however many children left it.
 ///
 /// Sends [`HierarchyEvent`]'s.
 fn update_old_parents(world: &mut World, parent: Entity, children: &[Entity]) {
     let mut events: SmallVec<[HierarchyEvent; 8]> = SmallVec::with_capacity(children.len());
+    let mut moved: EntityHashMap<EntityHashSet> = EntityHashMap::default();
     for &child in children {
         if let Some(previous) = update_parent(world, child, parent) {
             // Do nothing if the entity already has the correct parent.
@@ -104,7 +226,7 @@ fn update_old_parents(world: &mut World, parent: Entity, children: &[Entity]) {
                 continue;
             }
 
-            remove_from_children(world, previous, child);
+            moved.entry(previous).or_default().insert(child);
             events.push(HierarchyEvent::ChildMoved {
                 child,
                 previous_parent: previous,
@@ -114,6 +236,9 @@ fn update_old_parents(world: &mut World, parent: Entity, children: &[Entity]) {
             events.push(HierarchyEvent::ChildAdded { child, parent });
         }
     }
+    for (previous, moved_children) in &moved {
+        remove_all_from_children(world, *previous, moved_children);
+    }
     push_events(world, events);
 }
 
@@ -153,9 +278,37 @@ fn remove_children(parent: Entity, children: &[Entity], world: &mut World) {
 /// [`Parent`] component from its children.
 fn clear_children(parent: Entity, world: &mut World) {
     if let Some(children) = world.entity_mut(parent).take::<Children>() {
-        for &child in &children.0 {
-            world.entity_mut(child).remove::<Parent>();
-        }
+        remove_parents(world, children.0);
+    }
+}
+
+/// Replaces `parent`'s [`Children`] with `children`, removing the [`Children`] component if
+/// `children` is empty.
+///
+/// Children that are kept keep their [`Parent`] component, so only entities that actually join
+/// or leave the hierarchy are moved to a different archetype.
+///
+/// Sends [`HierarchyEvent`]'s for the children that were added or moved.
+fn replace_children(parent: Entity, children: &[Entity], world: &mut World) {
+    if let Some(previous_children) = world.get::<Children>(parent) {
+        let new_children: EntityHashSet = children.iter().copied().collect();
+        let removed: SmallVec<[Entity; 8]> = previous_children
+            .iter()
+            .copied()
+            .filter(|child| !new_children.contains(child))
+            .collect();
+        remove_parents(world, removed);
+    }
+    update_old_parents(world, parent, children);
+
+    let mut parent = world.entity_mut(parent);
+    if children.is_empty() {
+        parent.remove::<Children>();
+    } else if let Some(mut children_component) = parent.get_mut::<Children>() {
+        children_component.0.clear();
+        children_component.0.extend(children.iter().copied());
+    } else {
+        parent.insert(Children::from_entities(children));
     }
 }
 
@@ -190,6 +343,22 @@ impl Command for InsertChildren {
     }
 }
 
+/// Command that moves a child of an entity from one index of its [`Children`] to another.
+#[derive(Debug)]
+pub struct MoveChild {
+    parent: Entity,
+    from_index: usize,
+    to_index: usize,
+}
+
+impl Command for MoveChild {
+    fn apply(self, world: &mut World) {
+        world
+            .entity_mut(self.parent)
+            .move_child(self.from_index, self.to_index);
+    }
+}
+
 /// Command that pushes children to the end of the entity's [`Children`].
 #[derive(Debug)]
 pub struct PushChildren {
@@ -235,8 +404,9 @@ pub struct ReplaceChildren {
 
 impl Command for ReplaceChildren {
     fn apply(self, world: &mut World) {
-        clear_children(self.parent, world);
-        world.entity_mut(self.parent).push_children(&self.children);
+        world
+            .entity_mut(self.parent)
+            .replace_children(&self.children);
     }
 }
 
@@ -309,6 +479,10 @@ impl ChildBuilder<'_> {
 }
 
 /// Trait for removing, adding and replacing children and parents of an entity.
+///
+/// When the [`HierarchyCycleCheck`] resource exists, the queued commands reject changes that
+/// would make an entity a child of one of its descendants, since this would create a cycle in
+/// the hierarchy.
 pub trait BuildChildren {
     /// Takes a closure which builds children for this entity using [`ChildBuilder`].
     fn with_children(&mut self, f: impl FnOnce(&mut ChildBuilder)) -> &mut Self;
@@ -333,6 +507,10 @@ pub trait BuildChildren {
     ///
     /// Panics if any of the children are the same as the parent.
     fn insert_children(&mut self, index: usize, children: &[Entity]) -> &mut Self;
+    /// Moves the child at `from_index` to `to_index`, shifting the children in between.
+    ///
+    /// The command panics when applied if either index is out of bounds of the entity's [`Children`].
+    fn move_child(&mut self, from_index: usize, to_index: usize) -> &mut Self;
     /// Removes the given children
     ///
     /// Removing all children from a parent causes its [`Children`] component to be removed from the entity.
@@ -351,7 +529,8 @@ pub trait BuildChildren {
     fn clear_children(&mut self) -> &mut Self;
     /// Removes all current children from this entity, replacing them with the specified list of entities.
     ///
-    /// The removed children will have their [`Parent`] component removed.
+    /// The removed children will have their [`Parent`] component removed. Children that appear in
+    /// both the old and the new list keep their [`Parent`] component untouched.
     ///
     /// # Panics
     ///
@@ -419,6 +598,16 @@ impl BuildChildren for EntityCommands<'_> {
         self
     }
 
+    fn move_child(&mut self, from_index: usize, to_index: usize) -> &mut Self {
+        let parent = self.id();
+        self.commands().add(MoveChild {
+            parent,
+            from_index,
+            to_index,
+        });
+        self
+    }
+
     fn remove_children(&mut self, children: &[Entity]) -> &mut Self {
         let parent = self.id();
         self.commands().add(RemoveChildren {
@@ -528,7 +717,7 @@ pub trait BuildWorldChildren {
     ///
     /// # Panics
     ///
-    /// Panics if the child is the same as the parent.
+    /// Panics if the child is the same as the parent, or one of its ancestors.
     fn add_child(&mut self, child: Entity) -> &mut Self;
 
     /// Pushes children to the back of the builder's children. For any entities that are
@@ -540,7 +729,7 @@ pub trait BuildWorldChildren {
     ///
     /// # Panics
     ///
-    /// Panics if any of the children are the same as the parent.
+    /// Panics if any of the children are the same as the parent, or one of its ancestors.
     fn push_children(&mut self, children: &[Entity]) -> &mut Self;
     /// Inserts children at the given index.
     ///
@@ -550,8 +739,14 @@ pub trait BuildWorldChildren {
     ///
     /// # Panics
     ///
-    /// Panics if any of the children are the same as the parent.
+    /// Panics if any of the children are the same as the parent, or one of its ancestors.
     fn insert_children(&mut self, index: usize, children: &[Entity]) -> &mut Self;
+    /// Moves the child at `from_index` to `to_index`, shifting the children in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds of the entity's [`Children`].
+    fn move_child(&mut self, from_index: usize, to_index: usize) -> &mut Self;
     /// Removes the given children
     ///
     /// Removing all children from a parent causes its [`Children`] component to be removed from the entity.
@@ -565,7 +760,7 @@ pub trait BuildWorldChildren {
     ///
     /// # Panics
     ///
-    /// Panics if the parent is the same as the child.
+    /// Panics if the parent is the same as the child, or one of its descendants.
     fn set_parent(&mut self, parent: Entity) -> &mut Self;
 
     /// Removes the [`Parent`] of this entity.
@@ -577,11 +772,12 @@ pub trait BuildWorldChildren {
     fn clear_children(&mut self) -> &mut Self;
     /// Removes all current children from this entity, replacing them with the specified list of entities.
     ///
-    /// The removed children will have their [`Parent`] component removed.
+    /// The removed children will have their [`Parent`] component removed. Children that appear in
+    /// both the old and the new list keep their [`Parent`] component untouched.
     ///
     /// # Panics
     ///
-    /// Panics if any of the children are the same as the parent.
+    /// Panics if any of the children are the same as the parent, or one of its ancestors.
     fn replace_children(&mut self, children: &[Entity]) -> &mut Self;
 }
 
@@ -599,6 +795,9 @@ impl<'w> BuildWorldChildren for EntityWorldMut<'w> {
         if child == parent {
             panic!("Cannot add entity as a child of itself.");
         }
+        if !check_cycles(self.world(), parent, &[child]) {
+            return self;
+        }
         self.world_scope(|world| {
             update_old_parent(world, child, parent);
         });
@@ -616,6 +815,9 @@ impl<'w> BuildWorldChildren for EntityWorldMut<'w> {
         if children.contains(&parent) {
             panic!("Cannot push entity as a child of itself.");
         }
+        if !check_cycles(self.world(), parent, children) {
+            return self;
+        }
         self.world_scope(|world| {
             update_old_parents(world, parent, children);
         });
@@ -635,6 +837,9 @@ impl<'w> BuildWorldChildren for EntityWorldMut<'w> {
         if children.contains(&parent) {
             panic!("Cannot insert entity as a child of itself.");
         }
+        if !check_cycles(self.world(), parent, children) {
+            return self;
+        }
         self.world_scope(|world| {
             update_old_parents(world, parent, children);
         });
@@ -649,6 +854,14 @@ impl<'w> BuildWorldChildren for EntityWorldMut<'w> {
         self
     }
 
+    fn move_child(&mut self, from_index: usize, to_index: usize) -> &mut Self {
+        let Some(mut children) = self.get_mut::<Children>() else {
+            panic!("Cannot move a child of an entity without children.");
+        };
+        children.move_child(from_index, to_index);
+        self
+    }
+
     fn remove_children(&mut self, children: &[Entity]) -> &mut Self {
         let parent = self.id();
         self.world_scope(|world| {
@@ -685,13 +898,23 @@ impl<'w> BuildWorldChildren for EntityWorldMut<'w> {
     }
 
     fn replace_children(&mut self, children: &[Entity]) -> &mut Self {
-        self.clear_children().push_children(children)
+        let parent = self.id();
+        if children.contains(&parent) {
+            panic!("Cannot replace entity as a child of itself.");
+        }
+        if !check_cycles(self.world(), parent, children) {
+            return self;
+        }
+        self.world_scope(|world| {
+            replace_children(parent, children, world);
+        });
+        self
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{BuildChildren, BuildWorldChildren};
+    use super::{BuildChildren, BuildWorldChildren, HierarchyCycleCheck};
     use crate::{
         components::{Children, Parent},
         HierarchyEvent::{self, ChildAdded, ChildMoved, ChildRemoved},
@@ -702,6 +925,7 @@ mod tests {
         component::Component,
         entity::Entity,
         event::Events,
+        query::Changed,
         system::Commands,
         world::{CommandQueue, World},
     };
@@ -1026,6 +1250,72 @@ mod tests {
         assert!(world.get::<Parent>(child2).is_none());
     }
 
+    #[test]
+    fn replace_children_keeps_retained_children() {
+        let world = &mut World::new();
+        world.insert_resource(Events::<HierarchyEvent>::default());
+
+        let [parent, child1, child2, child3] = std::array::from_fn(|_| world.spawn_empty().id());
+
+        world.entity_mut(parent).push_children(&[child1, child2]);
+        omit_events(world, 2); // Omit ChildAdded events.
+
+        world.entity_mut(parent).replace_children(&[child3, child1]);
+        assert_children(world, parent, Some(&[child3, child1]));
+        assert_parent(world, child1, Some(parent));
+        assert_parent(world, child2, None);
+        assert_parent(world, child3, Some(parent));
+        // `child1` was never detached, so only `child3` is reported.
+        assert_events(
+            world,
+            &[ChildAdded {
+                child: child3,
+                parent,
+            }],
+        );
+
+        world.entity_mut(parent).replace_children(&[]);
+        assert_children(world, parent, None);
+        assert_parent(world, child1, None);
+        assert_parent(world, child3, None);
+    }
+
+    #[test]
+    fn replace_children_does_not_change_retained_parents() {
+        let world = &mut World::new();
+
+        let [parent, child1, child2, child3] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(parent).push_children(&[child1, child2]);
+        world.clear_trackers();
+
+        world.entity_mut(parent).replace_children(&[child2, child3]);
+
+        let mut changed = world.query_filtered::<Entity, Changed<Parent>>();
+        let changed: Vec<Entity> = changed.iter(world).collect();
+        assert_eq!(changed, vec![child3]);
+    }
+
+    #[test]
+    fn push_children_moves_several_children_from_one_parent() {
+        let world = &mut World::new();
+
+        let [old_parent, new_parent, child1, child2, child3] =
+            std::array::from_fn(|_| world.spawn_empty().id());
+        world
+            .entity_mut(old_parent)
+            .push_children(&[child1, child2, child3]);
+
+        world
+            .entity_mut(new_parent)
+            .push_children(&[child1, child3]);
+        assert_children(world, old_parent, Some(&[child2]));
+        assert_children(world, new_parent, Some(&[child1, child3]));
+
+        world.entity_mut(new_parent).push_children(&[child2]);
+        assert_children(world, old_parent, None);
+        assert_children(world, new_parent, Some(&[child1, child3, child2]));
+    }
+
     #[test]
     fn push_and_insert_and_remove_children_world() {
         let mut world = World::default();
@@ -1242,4 +1532,82 @@ mod tests {
         let children = query.get(&world, parent).unwrap();
         assert_eq!(**children, [child]);
     }
+
+    #[test]
+    fn move_child_world_and_commands() {
+        let mut world = World::new();
+        let [parent, a, b, c] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(parent).push_children(&[a, b, c]);
+
+        world.entity_mut(parent).move_child(0, 2);
+        assert_children(&world, parent, Some(&[b, c, a]));
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(parent).move_child(2, 0);
+        queue.apply(&mut world);
+        assert_children(&world, parent, Some(&[a, b, c]));
+    }
+
+    #[test]
+    #[should_panic(expected = "would create a cycle")]
+    fn add_ancestor_as_child_panics() {
+        let mut world = World::new();
+        world.init_resource::<HierarchyCycleCheck>();
+        let [a, b, c] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).add_child(b);
+        world.entity_mut(b).add_child(c);
+
+        world.entity_mut(c).push_children(&[a]);
+    }
+
+    #[test]
+    #[should_panic(expected = "would create a cycle")]
+    fn set_parent_to_descendant_commands_panics() {
+        let mut world = World::new();
+        world.init_resource::<HierarchyCycleCheck>();
+        let [a, b] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).add_child(b);
+
+        let mut queue = CommandQueue::default();
+        let mut commands = Commands::new(&mut queue, &world);
+        commands.entity(a).set_parent(b);
+        queue.apply(&mut world);
+    }
+
+    #[test]
+    fn cycle_check_is_opt_in() {
+        let mut world = World::new();
+        let [a, b] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).add_child(b);
+
+        world.entity_mut(b).add_child(a);
+        assert_parent(&world, a, Some(b));
+    }
+
+    #[test]
+    fn cycle_check_error_skips_change() {
+        let mut world = World::new();
+        world.insert_resource(HierarchyCycleCheck::Error);
+        let [a, b, c] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).add_child(b);
+        world.entity_mut(b).add_child(c);
+
+        world.entity_mut(c).push_children(&[a]);
+        assert_parent(&world, a, None);
+        assert_children(&world, c, None);
+    }
+
+    #[test]
+    #[should_panic(expected = "already contain a cycle")]
+    fn cycle_check_reports_existing_cycle() {
+        let mut world = World::new();
+        let [a, b, c] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).add_child(b);
+        // Create a cycle without the check, as direct component edits could.
+        world.entity_mut(b).add_child(a);
+
+        world.init_resource::<HierarchyCycleCheck>();
+        world.entity_mut(a).add_child(c);
+    }
 }
@@ -12,6 +12,9 @@ use std::ops::Deref;
 
 /// Contains references to the child entities of this entity.
 ///
+/// Children are kept in a stable order: new children are appended or inserted at the requested index,
+/// and the order only changes when explicitly rearranged, for example with [`Children::move_child`].
+///
 /// Each child must contain a [`Parent`] component that points back to this entity.
 /// This component rarely needs to be created manually,
 /// consider using higher level utilities like [`BuildChildren::with_children`]
@@ -60,6 +63,17 @@ impl Children {
         self.0.swap(a_index, b_index);
     }
 
+    /// Moves the child at `from_index` to `to_index`, shifting the children in between.
+    ///
+    /// # Panics
+    ///
+    /// Panics if either index is out of bounds.
+    #[inline]
+    pub fn move_child(&mut self, from_index: usize, to_index: usize) {
+        let child = self.0.remove(from_index);
+        self.0.insert(to_index, child);
+    }
+
     /// Sorts children [stably](https://en.wikipedia.org/wiki/Sorting_algorithm#Stability)
     /// in place using the provided comparator function.
     ///
@@ -0,0 +1,151 @@
+#[cfg(feature = "reflect")]
+use bevy_ecs::reflect::ReflectComponent;
+use bevy_ecs::{entity::EntityHashSet, prelude::*};
+
+use crate::{Children, HierarchyQueryExt, Parent};
+
+/// The number of ancestors of this entity, kept up to date by [`update_hierarchy_depth`].
+///
+/// Root entities have a depth of `0`, their children a depth of `1`, and so on.
+/// Insert this component on the entities whose depth you want to track and add
+/// [`HierarchyDepthPlugin`] to your app; other entities are not affected.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "reflect", derive(bevy_reflect::Reflect))]
+#[cfg_attr(feature = "reflect", reflect(Component, PartialEq))]
+pub struct HierarchyDepth(pub(crate) u32);
+
+impl HierarchyDepth {
+    /// Gets the number of ancestors of this entity.
+    #[inline]
+    pub fn get(&self) -> u32 {
+        self.0
+    }
+}
+
+/// Updates the [`HierarchyDepth`] of every entity whose [`Parent`] changed, and of all its descendants.
+///
+/// Each changed subtree is only walked once, even if several entities in it changed.
+pub fn update_hierarchy_depth(
+    changed_query: Query<Entity, Changed<Parent>>,
+    mut orphaned: RemovedComponents<Parent>,
+    parent_query: Query<&Parent>,
+    children_query: Query<&Children>,
+    mut depth_queries: ParamSet<(
+        Query<Entity, Added<HierarchyDepth>>,
+        Query<&mut HierarchyDepth>,
+    )>,
+    mut dirty: Local<EntityHashSet>,
+) {
+    dirty.extend(changed_query.iter().chain(orphaned.read()));
+    dirty.extend(depth_queries.p0().iter());
+
+    let mut depth_query = depth_queries.p1();
+    for &entity in dirty.iter() {
+        let mut depth = 0;
+        let mut has_dirty_ancestor = false;
+        for ancestor in parent_query.iter_ancestors(entity) {
+            if dirty.contains(&ancestor) {
+                has_dirty_ancestor = true;
+                break;
+            }
+            depth += 1;
+        }
+        // The dirty ancestor's walk already covers this entity.
+        if !has_dirty_ancestor {
+            set_depth_recursive(entity, depth, &children_query, &mut depth_query);
+        }
+    }
+    dirty.clear();
+}
+
+fn set_depth_recursive(
+    entity: Entity,
+    depth: u32,
+    children_query: &Query<&Children>,
+    depth_query: &mut Query<&mut HierarchyDepth>,
+) {
+    if let Ok(mut entity_depth) = depth_query.get_mut(entity) {
+        entity_depth.set_if_neq(HierarchyDepth(depth));
+    }
+    for &child in children_query.get(entity).into_iter().flatten() {
+        set_depth_recursive(child, depth + 1, children_query, depth_query);
+    }
+}
+
+/// Keeps [`HierarchyDepth`] components up to date.
+///
+/// Runs [`update_hierarchy_depth`] in [`PostUpdate`](bevy_app::PostUpdate).
+#[cfg(feature = "bevy_app")]
+#[derive(Default)]
+pub struct HierarchyDepthPlugin;
+
+#[cfg(feature = "bevy_app")]
+impl bevy_app::Plugin for HierarchyDepthPlugin {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.register_type::<HierarchyDepth>()
+            .add_systems(bevy_app::PostUpdate, update_hierarchy_depth);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{schedule::Schedule, world::World};
+
+    use super::{update_hierarchy_depth, HierarchyDepth};
+    use crate::BuildWorldChildren;
+
+    #[test]
+    fn depth_follows_hierarchy_changes() {
+        let world = &mut World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_hierarchy_depth);
+
+        let [a, b, c] = std::array::from_fn(|_| world.spawn(HierarchyDepth::default()).id());
+        // `d` doesn't track its depth, but still counts as an ancestor.
+        let d = world.spawn_empty().id();
+
+        world.entity_mut(d).push_children(&[a]);
+        world.entity_mut(a).push_children(&[b]);
+        world.entity_mut(b).push_children(&[c]);
+        schedule.run(world);
+        let depths = |world: &World| {
+            [a, b, c].map(|entity| world.get::<HierarchyDepth>(entity).unwrap().get())
+        };
+        assert_eq!([1, 2, 3], depths(world));
+
+        // Detaching a subtree updates all of its descendants.
+        world.entity_mut(b).remove_parent();
+        schedule.run(world);
+        assert_eq!([1, 0, 1], depths(world));
+
+        world.entity_mut(c).push_children(&[a]);
+        schedule.run(world);
+        assert_eq!([2, 0, 1], depths(world));
+    }
+
+    #[test]
+    fn depth_updates_nested_changes_and_added_components() {
+        let world = &mut World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(update_hierarchy_depth);
+
+        let [a, b, c] = std::array::from_fn(|_| world.spawn(HierarchyDepth::default()).id());
+        let d = world.spawn_empty().id();
+        schedule.run(world);
+
+        // Both `b` and `c` change parent in the same frame, with `c` under `b`.
+        world.entity_mut(a).push_children(&[b]);
+        world.entity_mut(b).push_children(&[c]);
+        world.entity_mut(d).push_children(&[a]);
+        schedule.run(world);
+        let depths = |world: &World| {
+            [a, b, c].map(|entity| world.get::<HierarchyDepth>(entity).unwrap().get())
+        };
+        assert_eq!([1, 2, 3], depths(world));
+
+        // Inserting the component on an existing entity computes its depth.
+        world.entity_mut(d).insert(HierarchyDepth(7));
+        schedule.run(world);
+        assert_eq!(0, world.get::<HierarchyDepth>(d).unwrap().get());
+    }
+}
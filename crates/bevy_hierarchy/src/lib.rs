@@ -22,7 +22,9 @@
 //! More advanced users may also appreciate
 //! [query extension methods] to traverse hierarchies,
 //! and [events] to notify hierarchical changes.
-//! There is also a [diagnostic plugin] to validate property propagation.
+//! User components can be [propagated] down the hierarchy,
+//! and there is a [diagnostic plugin] to validate property propagation,
+//! and a [consistency check] to audit the hierarchy itself.
 //!
 //! # Hierarchy management
 //!
@@ -45,10 +47,12 @@
 //! Instead, you should use the provided [hierarchical despawn extension methods].
 //!
 //! [command]: BuildChildren
+//! [consistency check]: validate_hierarchy
 //! [diagnostic plugin]: ValidParentCheckPlugin
 //! [events]: HierarchyEvent
 //! [hierarchical despawn extension methods]: DespawnRecursiveExt
 //! [plugin]: HierarchyPlugin
+//! [propagated]: Propagate
 //! [query extension methods]: HierarchyQueryExt
 //! [world]: BuildWorldChildren
 
@@ -70,14 +74,31 @@ pub use valid_parent_check_plugin::*;
 mod query_extension;
 pub use query_extension::*;
 
+mod validation;
+pub use validation::*;
+
+mod depth;
+pub use depth::*;
+
+mod propagate;
+pub use propagate::*;
+
+mod sort;
+pub use sort::*;
+
 #[doc(hidden)]
 pub mod prelude {
     #[doc(hidden)]
-    pub use crate::{child_builder::*, components::*, hierarchy::*, query_extension::*};
+    pub use crate::{
+        child_builder::*, components::*, depth::HierarchyDepth, hierarchy::*, query_extension::*,
+    };
 
     #[doc(hidden)]
     #[cfg(feature = "bevy_app")]
-    pub use crate::{HierarchyPlugin, ValidParentCheckPlugin};
+    pub use crate::{
+        HierarchyDepthPlugin, HierarchyPlugin, PropagatePlugin, SortChildrenPlugin,
+        ValidParentCheckPlugin,
+    };
 }
 
 #[cfg(feature = "bevy_app")]
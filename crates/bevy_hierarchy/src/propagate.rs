@@ -0,0 +1,235 @@
+#[cfg(feature = "bevy_app")]
+use std::marker::PhantomData;
+
+use bevy_ecs::{entity::EntityHashSet, prelude::*};
+
+use crate::{Children, Parent};
+
+/// A component whose value is combined down the hierarchy, from parents to children.
+///
+/// Each entity with both `Self` and [`Propagate::Inherited`] gets its inherited value computed
+/// from its own value and the inherited value of its parent by [`propagate_hierarchy`].
+/// Entities missing either component interrupt the propagation: their children are treated
+/// as roots.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_hierarchy::Propagate;
+/// #[derive(Component)]
+/// struct Opacity(f32);
+///
+/// #[derive(Component, PartialEq)]
+/// struct InheritedOpacity(f32);
+///
+/// impl Propagate for Opacity {
+///     type Inherited = InheritedOpacity;
+///
+///     fn propagate(&self, parent: Option<&InheritedOpacity>) -> InheritedOpacity {
+///         InheritedOpacity(self.0 * parent.map_or(1.0, |parent| parent.0))
+///     }
+/// }
+/// ```
+pub trait Propagate: Component {
+    /// The component holding the combined value.
+    type Inherited: Component + PartialEq;
+
+    /// Combines this entity's value with the inherited value of its parent,
+    /// which is `None` for roots.
+    fn propagate(&self, parent: Option<&Self::Inherited>) -> Self::Inherited;
+}
+
+/// Updates [`Propagate::Inherited`] for every entity whose `P` or [`Parent`] changed,
+/// or whose [`Propagate::Inherited`] was just added, and for all of its descendants.
+///
+/// Children of an entity that lost `P` or [`Propagate::Inherited`] become roots,
+/// so their subtrees are recomputed as well.
+///
+/// Unchanged subtrees are skipped, and inherited values are only written when they differ,
+/// so change detection on [`Propagate::Inherited`] is precise.
+pub fn propagate_hierarchy<P: Propagate>(
+    source_query: Query<(Entity, Ref<P>, Option<Ref<Parent>>), With<P::Inherited>>,
+    mut inherited_query: Query<&mut P::Inherited>,
+    children_query: Query<&Children>,
+    mut orphaned: RemovedComponents<Parent>,
+    mut removed_sources: RemovedComponents<P>,
+    mut removed_inherited: RemovedComponents<P::Inherited>,
+    mut detached: Local<EntityHashSet>,
+) {
+    detached.extend(orphaned.read());
+    for entity in removed_sources.read().chain(removed_inherited.read()) {
+        detached.extend(children_query.get(entity).into_iter().flatten());
+    }
+    for (entity, _, parent) in &source_query {
+        let parent = parent.map(|parent| parent.get());
+        if parent.is_some_and(|parent| source_query.contains(parent)) {
+            continue;
+        }
+        let dirty = detached.contains(&entity);
+        propagate_recursive(
+            entity,
+            None,
+            dirty,
+            &source_query,
+            &mut inherited_query,
+            &children_query,
+        );
+    }
+    detached.clear();
+}
+
+fn propagate_recursive<P: Propagate>(
+    entity: Entity,
+    parent: Option<Entity>,
+    parent_dirty: bool,
+    source_query: &Query<(Entity, Ref<P>, Option<Ref<Parent>>), With<P::Inherited>>,
+    inherited_query: &mut Query<&mut P::Inherited>,
+    children_query: &Query<&Children>,
+) {
+    let Ok((_, source, parent_ref)) = source_query.get(entity) else {
+        return;
+    };
+    // An `Added<P::Inherited>` filter would conflict with `inherited_query`, so check it directly.
+    let inherited_added = inherited_query
+        .get_mut(entity)
+        .is_ok_and(|inherited| inherited.is_added());
+    let dirty = parent_dirty
+        || inherited_added
+        || source.is_changed()
+        || parent_ref.is_some_and(|parent_ref| parent_ref.is_changed());
+    if dirty {
+        let value = source.propagate(parent.and_then(|parent| inherited_query.get(parent).ok()));
+        if let Ok(mut inherited) = inherited_query.get_mut(entity) {
+            inherited.set_if_neq(value);
+        }
+    }
+
+    for &child in children_query.get(entity).into_iter().flatten() {
+        propagate_recursive(
+            child,
+            Some(entity),
+            dirty,
+            source_query,
+            inherited_query,
+            children_query,
+        );
+    }
+}
+
+/// Keeps [`Propagate::Inherited`] up to date for `P`.
+///
+/// Runs [`propagate_hierarchy::<P>`](propagate_hierarchy) in [`PostUpdate`](bevy_app::PostUpdate).
+#[cfg(feature = "bevy_app")]
+pub struct PropagatePlugin<P: Propagate>(PhantomData<fn() -> P>);
+
+#[cfg(feature = "bevy_app")]
+impl<P: Propagate> Default for PropagatePlugin<P> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(feature = "bevy_app")]
+impl<P: Propagate> bevy_app::Plugin for PropagatePlugin<P> {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(bevy_app::PostUpdate, propagate_hierarchy::<P>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use super::{propagate_hierarchy, Propagate};
+    use crate::BuildWorldChildren;
+
+    #[derive(Component)]
+    struct Offset(u32);
+
+    #[derive(Component, PartialEq)]
+    struct TotalOffset(u32);
+
+    impl Propagate for Offset {
+        type Inherited = TotalOffset;
+
+        fn propagate(&self, parent: Option<&TotalOffset>) -> TotalOffset {
+            TotalOffset(self.0 + parent.map_or(0, |parent| parent.0))
+        }
+    }
+
+    #[test]
+    fn propagates_down_the_hierarchy() {
+        let world = &mut World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(propagate_hierarchy::<Offset>);
+
+        let [a, b, c] =
+            std::array::from_fn(|i| world.spawn((Offset(i as u32 + 1), TotalOffset(0))).id());
+        world.entity_mut(a).push_children(&[b]);
+        world.entity_mut(b).push_children(&[c]);
+        schedule.run(world);
+        let totals =
+            |world: &World| [a, b, c].map(|entity| world.get::<TotalOffset>(entity).unwrap().0);
+        assert_eq!([1, 3, 6], totals(world));
+
+        world.get_mut::<Offset>(a).unwrap().0 = 10;
+        schedule.run(world);
+        assert_eq!([10, 12, 15], totals(world));
+
+        // Unchanged subtrees are left alone.
+        world.clear_trackers();
+        schedule.run(world);
+        let mut changed = world.query_filtered::<Entity, Changed<TotalOffset>>();
+        assert_eq!(0, changed.iter(world).count());
+
+        world.entity_mut(b).remove_parent();
+        schedule.run(world);
+        assert_eq!([10, 2, 5], totals(world));
+    }
+
+    #[test]
+    fn recomputes_children_of_removed_sources() {
+        let world = &mut World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(propagate_hierarchy::<Offset>);
+
+        let [a, b, c] =
+            std::array::from_fn(|i| world.spawn((Offset(i as u32 + 1), TotalOffset(0))).id());
+        world.entity_mut(a).push_children(&[b]);
+        world.entity_mut(b).push_children(&[c]);
+        schedule.run(world);
+        assert_eq!(6, world.get::<TotalOffset>(c).unwrap().0);
+
+        // `b` no longer takes part in the propagation, so `c` becomes a root.
+        world.clear_trackers();
+        world.entity_mut(b).remove::<Offset>();
+        schedule.run(world);
+        assert_eq!(3, world.get::<TotalOffset>(c).unwrap().0);
+
+        world.entity_mut(b).insert(Offset(2));
+        schedule.run(world);
+        assert_eq!(6, world.get::<TotalOffset>(c).unwrap().0);
+
+        world.clear_trackers();
+        world.entity_mut(b).remove::<TotalOffset>();
+        schedule.run(world);
+        assert_eq!(3, world.get::<TotalOffset>(c).unwrap().0);
+    }
+
+    #[test]
+    fn propagates_to_added_inherited() {
+        let world = &mut World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(propagate_hierarchy::<Offset>);
+
+        let a = world.spawn((Offset(1), TotalOffset(0))).id();
+        let b = world.spawn(Offset(2)).id();
+        world.entity_mut(a).push_children(&[b]);
+        schedule.run(world);
+        world.clear_trackers();
+
+        // Neither `Offset` nor `Parent` changed, but the inherited value must still be computed.
+        world.entity_mut(b).insert(TotalOffset(0));
+        schedule.run(world);
+        assert_eq!(3, world.get::<TotalOffset>(b).unwrap().0);
+    }
+}
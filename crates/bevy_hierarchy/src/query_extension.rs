@@ -34,6 +34,45 @@ pub trait HierarchyQueryExt<'w, 's, D: QueryData, F: QueryFilter> {
     where
         D::ReadOnly: WorldQuery<Item<'w> = &'w Children>;
 
+    /// Returns an [`Iterator`] of [`Entity`]s over the descendants of `entity` that pass `predicate`.
+    ///
+    /// Can only be called on a [`Query`] of [`Children`] (i.e. `Query<&Children>`).
+    ///
+    /// Traverses the hierarchy breadth-first. Descendants for which `predicate` returns `false`
+    /// are skipped along with their entire subtree, so whole branches can be pruned without
+    /// being visited. Use [`Query::contains`] in the predicate to apply a [`QueryFilter`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_hierarchy::prelude::*;
+    /// # #[derive(Component)]
+    /// # struct Marker;
+    /// # #[derive(Component)]
+    /// # struct Hidden;
+    /// fn system(
+    ///     query: Query<Entity, With<Marker>>,
+    ///     children_query: Query<&Children>,
+    ///     visible_query: Query<(), Without<Hidden>>,
+    /// ) {
+    ///     let entity = query.single();
+    ///     for descendant in children_query
+    ///         .iter_descendants_filtered(entity, |descendant| visible_query.contains(descendant))
+    ///     {
+    ///         // Do something!
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    fn iter_descendants_filtered<P>(
+        &'w self,
+        entity: Entity,
+        predicate: P,
+    ) -> FilteredDescendantIter<'w, 's, D, F, P>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+        P: FnMut(Entity) -> bool;
+
     /// Returns an [`Iterator`] of [`Entity`]s over all of `entity`s ancestors.
     ///
     /// Can only be called on a [`Query`] of [`Parent`] (i.e. `Query<&Parent>`).
@@ -65,6 +104,18 @@ impl<'w, 's, D: QueryData, F: QueryFilter> HierarchyQueryExt<'w, 's, D, F> for Q
         DescendantIter::new(self, entity)
     }
 
+    fn iter_descendants_filtered<P>(
+        &'w self,
+        entity: Entity,
+        predicate: P,
+    ) -> FilteredDescendantIter<'w, 's, D, F, P>
+    where
+        D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+        P: FnMut(Entity) -> bool,
+    {
+        FilteredDescendantIter::new(self, entity, predicate)
+    }
+
     fn iter_ancestors(&'w self, entity: Entity) -> AncestorIter<'w, 's, D, F>
     where
         D::ReadOnly: WorldQuery<Item<'w> = &'w Parent>,
@@ -119,6 +170,62 @@ where
     }
 }
 
+/// An [`Iterator`] of [`Entity`]s over the descendants of an [`Entity`] that pass a predicate.
+///
+/// Traverses the hierarchy breadth-first, skipping the subtrees of rejected descendants.
+pub struct FilteredDescendantIter<'w, 's, D: QueryData, F: QueryFilter, P>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+    P: FnMut(Entity) -> bool,
+{
+    children_query: &'w Query<'w, 's, D, F>,
+    vecdeque: VecDeque<Entity>,
+    predicate: P,
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, P> FilteredDescendantIter<'w, 's, D, F, P>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+    P: FnMut(Entity) -> bool,
+{
+    /// Returns a new [`FilteredDescendantIter`].
+    pub fn new(children_query: &'w Query<'w, 's, D, F>, entity: Entity, predicate: P) -> Self {
+        FilteredDescendantIter {
+            children_query,
+            vecdeque: children_query
+                .get(entity)
+                .into_iter()
+                .flatten()
+                .copied()
+                .collect(),
+            predicate,
+        }
+    }
+}
+
+impl<'w, 's, D: QueryData, F: QueryFilter, P> Iterator for FilteredDescendantIter<'w, 's, D, F, P>
+where
+    D::ReadOnly: WorldQuery<Item<'w> = &'w Children>,
+    P: FnMut(Entity) -> bool,
+{
+    type Item = Entity;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let entity = self.vecdeque.pop_front()?;
+            if !(self.predicate)(entity) {
+                continue;
+            }
+
+            if let Ok(children) = self.children_query.get(entity) {
+                self.vecdeque.extend(children);
+            }
+
+            return Some(entity);
+        }
+    }
+}
+
 /// An [`Iterator`] of [`Entity`]s over the ancestors of an [`Entity`].
 pub struct AncestorIter<'w, 's, D: QueryData, F: QueryFilter>
 where
@@ -185,6 +292,37 @@ mod tests {
         assert_eq!([&A(1), &A(2), &A(3)], result.as_slice());
     }
 
+    #[test]
+    fn filtered_descendant_iter() {
+        let world = &mut World::new();
+
+        let [a, b, c, d, e] = std::array::from_fn(|i| world.spawn(A(i)).id());
+
+        world.entity_mut(a).push_children(&[b, c]);
+        world.entity_mut(b).push_children(&[d]);
+        world.entity_mut(c).push_children(&[e]);
+
+        let mut system_state = SystemState::<(Query<&Children>, Query<&A>)>::new(world);
+        let (children_query, a_query) = system_state.get(world);
+
+        // Rejecting `b` also prunes its child `d`.
+        let result: Vec<_> = a_query
+            .iter_many(children_query.iter_descendants_filtered(a, |entity| entity != b))
+            .collect();
+        assert_eq!([&A(2), &A(4)], result.as_slice());
+
+        // The iterator is lazy, so traversal stops once a match is found.
+        let mut visited = 0;
+        let found = children_query
+            .iter_descendants_filtered(a, |_| {
+                visited += 1;
+                true
+            })
+            .find(|&entity| entity == c);
+        assert_eq!(Some(c), found);
+        assert_eq!(2, visited);
+    }
+
     #[test]
     fn ancestor_iter() {
         let world = &mut World::new();
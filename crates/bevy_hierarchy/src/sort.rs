@@ -0,0 +1,121 @@
+#[cfg(feature = "bevy_app")]
+use std::marker::PhantomData;
+
+use bevy_ecs::{entity::EntityHashSet, prelude::*};
+
+use crate::{Children, Parent};
+
+/// Keeps every [`Children`] component sorted by the `K` component of the children.
+///
+/// A parent is re-sorted when its [`Children`] changed, or when the `K` of one of its children
+/// was added, changed or removed. Children without `K` are placed first. The sort is stable, so
+/// children with equal keys keep their relative order, and [`Children`] is only marked as changed
+/// when the order actually differs.
+pub fn sort_children_by_key<K: Component + Ord>(
+    changed_keys: Query<&Parent, Changed<K>>,
+    mut removed_keys: RemovedComponents<K>,
+    parent_query: Query<&Parent>,
+    mut children_queries: ParamSet<(Query<Entity, Changed<Children>>, Query<&mut Children>)>,
+    key_query: Query<&K>,
+    mut parents: Local<EntityHashSet>,
+) {
+    parents.extend(changed_keys.iter().map(Parent::get));
+    parents.extend(
+        removed_keys
+            .read()
+            .filter_map(|child| parent_query.get(child).ok())
+            .map(Parent::get),
+    );
+    parents.extend(children_queries.p0().iter());
+
+    let mut children_query = children_queries.p1();
+    for parent in parents.drain() {
+        let Ok(mut children) = children_query.get_mut(parent) else {
+            continue;
+        };
+        let key = |child: &Entity| key_query.get(*child).ok();
+        if children
+            .windows(2)
+            .all(|pair| key(&pair[0]) <= key(&pair[1]))
+        {
+            continue;
+        }
+        children.sort_by(|a, b| key(a).cmp(&key(b)));
+    }
+}
+
+/// Keeps [`Children`] sorted by the `K` component of the children.
+///
+/// Runs [`sort_children_by_key::<K>`](sort_children_by_key) in [`PostUpdate`](bevy_app::PostUpdate).
+#[cfg(feature = "bevy_app")]
+pub struct SortChildrenPlugin<K: Component + Ord>(PhantomData<fn() -> K>);
+
+#[cfg(feature = "bevy_app")]
+impl<K: Component + Ord> Default for SortChildrenPlugin<K> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+#[cfg(feature = "bevy_app")]
+impl<K: Component + Ord> bevy_app::Plugin for SortChildrenPlugin<K> {
+    fn build(&self, app: &mut bevy_app::App) {
+        app.add_systems(bevy_app::PostUpdate, sort_children_by_key::<K>);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::prelude::*;
+
+    use super::sort_children_by_key;
+    use crate::{BuildWorldChildren, Children};
+
+    #[derive(Component, PartialEq, Eq, PartialOrd, Ord)]
+    struct Rank(u32);
+
+    #[test]
+    fn children_stay_sorted() {
+        let world = &mut World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(sort_children_by_key::<Rank>);
+
+        let parent = world.spawn_empty().id();
+        let a = world.spawn(Rank(2)).id();
+        let b = world.spawn(Rank(1)).id();
+        let c = world.spawn_empty().id();
+        world.entity_mut(parent).push_children(&[a, b, c]);
+        schedule.run(world);
+        assert_eq!(&[c, b, a], &**world.get::<Children>(parent).unwrap());
+
+        world.get_mut::<Rank>(b).unwrap().0 = 3;
+        schedule.run(world);
+        assert_eq!(&[c, a, b], &**world.get::<Children>(parent).unwrap());
+
+        // Already sorted children are not touched.
+        world.clear_trackers();
+        world.get_mut::<Rank>(a).unwrap().0 = 0;
+        schedule.run(world);
+        let mut changed = world.query_filtered::<(), Changed<Children>>();
+        assert_eq!(0, changed.iter(world).count());
+    }
+
+    #[test]
+    fn removing_key_resorts_children() {
+        let world = &mut World::new();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(sort_children_by_key::<Rank>);
+
+        let parent = world.spawn_empty().id();
+        let a = world.spawn(Rank(1)).id();
+        let b = world.spawn(Rank(2)).id();
+        world.entity_mut(parent).push_children(&[a, b]);
+        schedule.run(world);
+        assert_eq!(&[a, b], &**world.get::<Children>(parent).unwrap());
+
+        // Children without a key are placed first.
+        world.entity_mut(b).remove::<Rank>();
+        schedule.run(world);
+        assert_eq!(&[b, a], &**world.get::<Children>(parent).unwrap());
+    }
+}
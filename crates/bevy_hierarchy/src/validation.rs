@@ -0,0 +1,365 @@
+use std::fmt;
+
+use bevy_ecs::{component::ComponentId, entity::Entity, world::World};
+use smallvec::SmallVec;
+
+use crate::{Children, Parent};
+
+/// A mismatch between [`Parent`] and [`Children`] components, as reported by [`validate_hierarchy`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HierarchyInconsistency {
+    /// `child` has a [`Parent`] pointing to an entity that does not exist.
+    DanglingParent {
+        /// The entity holding the [`Parent`] component.
+        child: Entity,
+        /// The missing parent entity.
+        parent: Entity,
+    },
+    /// `child` has a [`Parent`] pointing to `parent`, but `parent`'s [`Children`] doesn't list `child`.
+    NotInChildren {
+        /// The entity holding the [`Parent`] component.
+        child: Entity,
+        /// The parent entity.
+        parent: Entity,
+    },
+    /// `parent`'s [`Children`] lists an entity that does not exist.
+    DanglingChild {
+        /// The entity holding the [`Children`] component.
+        parent: Entity,
+        /// The missing child entity.
+        child: Entity,
+    },
+    /// `parent`'s [`Children`] lists `child`, but `child`'s [`Parent`] is missing or points elsewhere.
+    WrongParent {
+        /// The entity holding the [`Children`] component.
+        parent: Entity,
+        /// The child entity.
+        child: Entity,
+        /// The parent `child` actually points to, if any.
+        actual_parent: Option<Entity>,
+    },
+}
+
+impl HierarchyInconsistency {
+    /// Returns the [`ComponentId`] of the component holding the broken reference:
+    /// [`Parent`] for [`DanglingParent`](Self::DanglingParent) and [`NotInChildren`](Self::NotInChildren),
+    /// [`Children`] otherwise.
+    ///
+    /// Returns `None` if the component was never registered in `world`.
+    pub fn component_id(&self, world: &World) -> Option<ComponentId> {
+        match self {
+            Self::DanglingParent { .. } | Self::NotInChildren { .. } => {
+                world.component_id::<Parent>()
+            }
+            Self::DanglingChild { .. } | Self::WrongParent { .. } => {
+                world.component_id::<Children>()
+            }
+        }
+    }
+
+    /// Returns a [`Display`](fmt::Display) implementation that resolves the entities against `world`,
+    /// printing their `Name` alongside the [`Entity`] when they have one.
+    ///
+    /// Names are only resolved when the `bevy_app` feature (and so `bevy_core`) is enabled.
+    pub fn display<'w>(&self, world: &'w World) -> HierarchyInconsistencyDisplay<'w> {
+        HierarchyInconsistencyDisplay {
+            inconsistency: *self,
+            world,
+        }
+    }
+
+    fn write(&self, f: &mut fmt::Formatter<'_>, world: Option<&World>) -> fmt::Result {
+        let label = |entity| EntityLabel { entity, world };
+        match *self {
+            Self::DanglingParent { child, parent } => write!(
+                f,
+                "{} has a Parent component pointing to {}, which does not exist",
+                label(child),
+                label(parent),
+            ),
+            Self::NotInChildren { child, parent } => write!(
+                f,
+                "{} has a Parent component pointing to {}, whose Children component does not contain it",
+                label(child),
+                label(parent),
+            ),
+            Self::DanglingChild { parent, child } => write!(
+                f,
+                "{} has a Children component containing {}, which does not exist",
+                label(parent),
+                label(child),
+            ),
+            Self::WrongParent {
+                parent,
+                child,
+                actual_parent: Some(actual_parent),
+            } => write!(
+                f,
+                "{} has a Children component containing {}, whose Parent component points to {}",
+                label(parent),
+                label(child),
+                label(actual_parent),
+            ),
+            Self::WrongParent {
+                parent,
+                child,
+                actual_parent: None,
+            } => write!(
+                f,
+                "{} has a Children component containing {}, which has no Parent component",
+                label(parent),
+                label(child),
+            ),
+        }
+    }
+}
+
+impl fmt::Display for HierarchyInconsistency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write(f, None)
+    }
+}
+
+/// Displays a [`HierarchyInconsistency`] with the names of the entities involved.
+///
+/// Returned by [`HierarchyInconsistency::display`].
+pub struct HierarchyInconsistencyDisplay<'w> {
+    inconsistency: HierarchyInconsistency,
+    world: &'w World,
+}
+
+impl fmt::Display for HierarchyInconsistencyDisplay<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inconsistency.write(f, Some(self.world))
+    }
+}
+
+/// Formats an [`Entity`], followed by its `Name` if it has one in `world`.
+struct EntityLabel<'w> {
+    entity: Entity,
+    #[cfg_attr(not(feature = "bevy_core"), allow(dead_code))]
+    world: Option<&'w World>,
+}
+
+impl fmt::Display for EntityLabel<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self.entity)?;
+        #[cfg(feature = "bevy_core")]
+        if let Some(name) = self
+            .world
+            .and_then(|world| world.get::<bevy_core::Name>(self.entity))
+        {
+            write!(f, " ({name})")?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks that every [`Parent`] and [`Children`] component in `world` agree with each other,
+/// and that none of them refer to despawned entities.
+///
+/// The hierarchy can only get out of sync through manual manipulation of these components,
+/// or by despawning entities without using [`DespawnRecursiveExt`](crate::DespawnRecursiveExt).
+/// This walks every entity in the world, so it is meant for debugging and tests.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_hierarchy::{validate_hierarchy, BuildWorldChildren};
+/// let mut world = World::new();
+/// let parent = world.spawn_empty().id();
+/// let child = world.spawn_empty().id();
+/// world.entity_mut(parent).add_child(child);
+///
+/// assert!(validate_hierarchy(&world).is_empty());
+///
+/// // Despawning without `despawn_recursive` leaves the parent pointing at a missing entity.
+/// world.despawn(child);
+/// assert_eq!(validate_hierarchy(&world).len(), 1);
+/// ```
+pub fn validate_hierarchy(world: &World) -> Vec<HierarchyInconsistency> {
+    let mut inconsistencies = Vec::new();
+    for entity_ref in world.iter_entities() {
+        let entity = entity_ref.id();
+
+        if let Some(parent) = entity_ref.get::<Parent>().map(Parent::get) {
+            match world.get_entity(parent) {
+                None => inconsistencies.push(HierarchyInconsistency::DanglingParent {
+                    child: entity,
+                    parent,
+                }),
+                Some(parent_ref) => {
+                    if !parent_ref
+                        .get::<Children>()
+                        .is_some_and(|children| children.contains(&entity))
+                    {
+                        inconsistencies.push(HierarchyInconsistency::NotInChildren {
+                            child: entity,
+                            parent,
+                        });
+                    }
+                }
+            }
+        }
+
+        if let Some(children) = entity_ref.get::<Children>() {
+            for &child in children {
+                match world.get_entity(child) {
+                    None => inconsistencies.push(HierarchyInconsistency::DanglingChild {
+                        parent: entity,
+                        child,
+                    }),
+                    Some(child_ref) => {
+                        let actual_parent = child_ref.get::<Parent>().map(Parent::get);
+                        if actual_parent != Some(entity) {
+                            inconsistencies.push(HierarchyInconsistency::WrongParent {
+                                parent: entity,
+                                child,
+                                actual_parent,
+                            });
+                        }
+                    }
+                }
+            }
+        }
+    }
+    inconsistencies
+}
+
+/// Removes the entries of [`Children`] components that refer to despawned entities,
+/// and returns how many entries were removed.
+///
+/// If `remove_empty` is `true`, [`Children`] components left empty are removed as well,
+/// matching what the hierarchy commands do.
+///
+/// Despawning entities with [`DespawnRecursiveExt`](crate::DespawnRecursiveExt) never leaves
+/// such entries behind. This is meant to repair worlds where entities were despawned directly.
+pub fn remove_dangling_children(world: &mut World, remove_empty: bool) -> usize {
+    let mut query = world.query::<(Entity, &Children)>();
+    let dangling: Vec<(Entity, SmallVec<[Entity; 8]>)> = query
+        .iter(world)
+        .filter_map(|(parent, children)| {
+            let dangling: SmallVec<[Entity; 8]> = children
+                .iter()
+                .copied()
+                .filter(|&child| !world.entities().contains(child))
+                .collect();
+            (!dangling.is_empty()).then_some((parent, dangling))
+        })
+        .collect();
+
+    let mut removed = 0;
+    for (parent, dangling) in dangling {
+        let mut parent = world.entity_mut(parent);
+        let Some(mut children) = parent.get_mut::<Children>() else {
+            continue;
+        };
+        children.0.retain(|child| !dangling.contains(child));
+        removed += dangling.len();
+        if remove_empty && children.is_empty() {
+            parent.remove::<Children>();
+        }
+    }
+    removed
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::world::World;
+
+    use super::{remove_dangling_children, validate_hierarchy, HierarchyInconsistency};
+    use crate::{BuildWorldChildren, Children, Parent};
+
+    #[test]
+    fn reports_mismatched_components() {
+        let world = &mut World::new();
+
+        let [a, b, c, d] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).push_children(&[b, c]);
+        assert!(validate_hierarchy(world).is_empty());
+
+        // Point `c` at `d` without updating either `Children` component.
+        world.entity_mut(c).insert(Parent(d));
+        let mut inconsistencies = validate_hierarchy(world);
+        inconsistencies.sort_by_key(|inconsistency| {
+            matches!(inconsistency, HierarchyInconsistency::WrongParent { .. })
+        });
+        assert_eq!(
+            inconsistencies,
+            [
+                HierarchyInconsistency::NotInChildren {
+                    child: c,
+                    parent: d
+                },
+                HierarchyInconsistency::WrongParent {
+                    parent: a,
+                    child: c,
+                    actual_parent: Some(d)
+                },
+            ]
+        );
+
+        // Despawning non-recursively leaves dangling references on both sides.
+        world.entity_mut(c).remove::<Parent>();
+        world.despawn(a);
+        world.entity_mut(d).insert(Children::from_entities(&[c]));
+        world.despawn(c);
+        let mut inconsistencies = validate_hierarchy(world);
+        inconsistencies.sort_by_key(|inconsistency| {
+            matches!(inconsistency, HierarchyInconsistency::DanglingChild { .. })
+        });
+        assert_eq!(
+            inconsistencies,
+            [
+                HierarchyInconsistency::DanglingParent {
+                    child: b,
+                    parent: a
+                },
+                HierarchyInconsistency::DanglingChild {
+                    parent: d,
+                    child: c
+                },
+            ]
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "bevy_core")]
+    fn display_resolves_names() {
+        let world = &mut World::new();
+
+        let parent = world.spawn(bevy_core::Name::new("root")).id();
+        let child = world.spawn_empty().id();
+        world.entity_mut(child).insert(Parent(parent));
+
+        let inconsistencies = validate_hierarchy(world);
+        assert_eq!(
+            inconsistencies,
+            [HierarchyInconsistency::NotInChildren { child, parent }]
+        );
+        assert_eq!(
+            inconsistencies[0].component_id(world),
+            world.component_id::<Parent>()
+        );
+        assert_eq!(
+            inconsistencies[0].display(world).to_string(),
+            format!("{child:?} has a Parent component pointing to {parent:?} (root), whose Children component does not contain it")
+        );
+    }
+
+    #[test]
+    fn removes_dangling_children() {
+        let world = &mut World::new();
+
+        let [a, b, c, d, e] = std::array::from_fn(|_| world.spawn_empty().id());
+        world.entity_mut(a).push_children(&[b, c]);
+        world.entity_mut(d).push_children(&[e]);
+        world.despawn(b);
+        world.despawn(e);
+
+        assert_eq!(2, remove_dangling_children(world, true));
+        assert_eq!(&[c], &**world.get::<Children>(a).unwrap());
+        assert!(world.get::<Children>(d).is_none());
+        assert!(validate_hierarchy(world).is_empty());
+        assert_eq!(0, remove_dangling_children(world, true));
+    }
+}
@@ -2,7 +2,7 @@ use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
 use bevy_ecs::entity::EntityHashMap;
 use bevy_ecs::{
     entity::Entity,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectMapEntitiesResource},
     world::World,
 };
 use bevy_reflect::{Reflect, TypePath, TypeRegistry};
@@ -71,28 +71,6 @@ impl DynamicScene {
     ) -> Result<(), SceneSpawnError> {
         let type_registry = type_registry.read();
 
-        for resource in &self.resources {
-            let type_info = resource.get_represented_type_info().ok_or_else(|| {
-                SceneSpawnError::NoRepresentedType {
-                    type_path: resource.reflect_type_path().to_string(),
-                }
-            })?;
-            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
-                SceneSpawnError::UnregisteredButReflectedType {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
-                SceneSpawnError::UnregisteredResource {
-                    type_path: type_info.type_path().to_string(),
-                }
-            })?;
-
-            // If the world already contains an instance of the given resource
-            // just apply the (possibly) new value, otherwise insert the resource
-            reflect_resource.apply_or_insert(world, &**resource, &type_registry);
-        }
-
         // For each component types that reference other entities, we keep track
         // of which entities in the scene use that component.
         // This is so we can update the scene-internal references to references
@@ -153,6 +131,36 @@ impl DynamicScene {
             }
         }
 
+        // Insert resources after all entities have been added to the world.
+        // This ensures the entities are available for the resources to reference during mapping.
+        for resource in &self.resources {
+            let type_info = resource.get_represented_type_info().ok_or_else(|| {
+                SceneSpawnError::NoRepresentedType {
+                    type_path: resource.reflect_type_path().to_string(),
+                }
+            })?;
+            let registration = type_registry.get(type_info.type_id()).ok_or_else(|| {
+                SceneSpawnError::UnregisteredButReflectedType {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+            let reflect_resource = registration.data::<ReflectResource>().ok_or_else(|| {
+                SceneSpawnError::UnregisteredResource {
+                    type_path: type_info.type_path().to_string(),
+                }
+            })?;
+
+            // If the world already contains an instance of the given resource
+            // just apply the (possibly) new value, otherwise insert the resource
+            reflect_resource.apply_or_insert(world, &**resource, &type_registry);
+
+            // If this resource references entities in the scene, update
+            // them to the entities in the world.
+            if let Some(map_entities) = registration.data::<ReflectMapEntitiesResource>() {
+                map_entities.map_entities(world, entity_map);
+            }
+        }
+
         Ok(())
     }
 
@@ -198,9 +206,12 @@ where
 
 #[cfg(test)]
 mod tests {
-    use bevy_ecs::entity::EntityHashMap;
+    use bevy_ecs::entity::{Entity, EntityHashMap, MapEntities};
+    use bevy_ecs::reflect::{ReflectMapEntitiesResource, ReflectResource};
+    use bevy_ecs::system::Resource;
     use bevy_ecs::{reflect::AppTypeRegistry, world::Command, world::World};
     use bevy_hierarchy::{Parent, PushChild};
+    use bevy_reflect::Reflect;
 
     use crate::dynamic_scene_builder::DynamicSceneBuilder;
 
@@ -280,4 +291,36 @@ mod tests {
             "something is wrong with the this test or the code reloading scenes since the relationship between scene entities is broken"
         );
     }
+
+    #[test]
+    fn resources_are_entity_mapped() {
+        #[derive(Resource, Reflect, MapEntities)]
+        #[reflect(Resource, MapEntitiesResource)]
+        struct Target(Entity);
+
+        let type_registry = AppTypeRegistry::default();
+        type_registry.write().register::<Target>();
+
+        let mut source = World::new();
+        source.insert_resource(type_registry.clone());
+        let entity = source.spawn_empty().id();
+        source.insert_resource(Target(entity));
+        let scene = DynamicSceneBuilder::from_world(&source)
+            .extract_entity(entity)
+            .extract_resources()
+            .build();
+
+        let mut destination = World::new();
+        destination.insert_resource(type_registry);
+        // Make sure the scene entity gets a different id in the destination world.
+        destination.spawn_empty();
+        let mut entity_map = EntityHashMap::default();
+        scene
+            .write_to_world(&mut destination, &mut entity_map)
+            .unwrap();
+
+        let &mapped = entity_map.get(&entity).unwrap();
+        assert_ne!(mapped, entity);
+        assert_eq!(destination.resource::<Target>().0, mapped);
+    }
 }